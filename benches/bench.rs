@@ -1,3 +1,6 @@
+// The benchmarks measure the panicking constructors
+#![cfg_attr(feature = "panic_free", allow(deprecated))]
+
 use std::{mem::align_of, mem::size_of, rc::Rc, time::Duration};
 
 static DIVISORS: [(u32, [u32; 64]); 10001] = include!("divisors.txt");
@@ -9,8 +12,9 @@ fn get_divisors(n: u32) -> &'static [u32] {
 
 use bumpalo::{collections::Vec as BumpVec, Bump};
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use rc_bump::{Paving, RcBumpMember};
+use rc_bump::{Paving, RcBumpMember, ScopedMember};
 
+#[allow(dead_code)]
 struct GraphNodePaving {
     tag: u32,
     neighbors: Vec<RcBumpMember<GraphNodePaving>>,
@@ -45,6 +49,46 @@ fn generate_graph_paving(n: u32) {
     }
 }
 
+#[allow(dead_code)]
+struct GraphNodeGuard<'g> {
+    tag: u32,
+    neighbors: &'g [&'g GraphNodeGuard<'g>],
+}
+
+fn generate_graph_paving_guard(n: u32) {
+    let paving = Paving::new(
+        100 * size_of::<GraphNodeGuard>(),
+        align_of::<GraphNodeGuard>(),
+    );
+    let guard = paving.guard();
+    let mut nodes: Vec<&GraphNodeGuard> = Vec::new();
+    let mut children = Vec::new();
+    {
+        for i in 1_u32..n {
+            children.clear();
+            children.extend(
+                get_divisors(i)
+                    .iter()
+                    .filter(|&&k| i != k)
+                    .map(|k| nodes[*k as usize - 1]),
+            );
+            let neighbors = guard.try_alloc_slice_copy(&children).unwrap();
+            let node = GraphNodeGuard {
+                tag: i,
+                neighbors: ScopedMember::leak(neighbors),
+            };
+            let node = guard.try_alloc(node).ok().unwrap();
+            nodes.push(ScopedMember::leak(node));
+        }
+    }
+    let mut head = nodes.pop().unwrap();
+    std::mem::drop(nodes);
+    while let Some(new_head) = head.neighbors.last() {
+        head = new_head
+    }
+}
+
+#[allow(dead_code)]
 struct GraphNodeRc {
     tag: u32,
     neighbors: Vec<Rc<GraphNodeRc>>,
@@ -75,6 +119,7 @@ fn generate_graph_rc(n: u32) {
     }
 }
 
+#[allow(dead_code)]
 struct GraphNodeBumpalo<'a> {
     tag: u32,
     neighbors: BumpVec<'a, &'a GraphNodeBumpalo<'a>>,
@@ -117,6 +162,9 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         group.bench_with_input(BenchmarkId::new("Paving", n), &n, |b, &n| {
             b.iter(|| generate_graph_paving(n));
         });
+        group.bench_with_input(BenchmarkId::new("PavingGuard", n), &n, |b, &n| {
+            b.iter(|| generate_graph_paving_guard(n));
+        });
         group.bench_with_input(BenchmarkId::new("Rc", n), &n, |b, &n| {
             b.iter(|| generate_graph_rc(n));
         });
@@ -0,0 +1,77 @@
+//! Derive macros for [rc_bump](https://docs.rs/rc_bump).
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields};
+
+/// Derives `rc_bump::Columnar` for a struct with named fields.
+///
+/// A `<Name>Columns` struct is generated alongside, holding one
+/// `rc_bump::Column` per field, under the same name and visibility.
+#[proc_macro_derive(Columnar)]
+pub fn derive_columnar(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_columnar(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_columnar(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "Columnar can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Columnar can only be derived for structs",
+            ))
+        }
+    };
+    let vis = &input.vis;
+    let name = &input.ident;
+    let columns = format_ident!("{}Columns", name);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let names: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+    let vises = fields.iter().map(|field| &field.vis);
+    let tys = fields.iter().map(|field| &field.ty);
+    let doc = format!("The columns of [`{name}`], see `rc_bump::ColumnarPaving`.");
+    Ok(quote! {
+        #[doc = #doc]
+        #vis struct #columns #impl_generics #where_clause {
+            #(#vises #names: ::rc_bump::Column<#tys>,)*
+        }
+
+        impl #impl_generics ::rc_bump::Columnar for #name #ty_generics #where_clause {
+            type Columns = #columns #ty_generics;
+
+            fn try_columns(
+                rows_per_chunk: usize,
+            ) -> ::core::result::Result<Self::Columns, ::rc_bump::NewBumpError> {
+                ::core::result::Result::Ok(#columns {
+                    #(#names: ::rc_bump::Column::try_new(rows_per_chunk)?,)*
+                })
+            }
+
+            fn try_push(
+                columns: &mut Self::Columns,
+                value: Self,
+            ) -> ::core::result::Result<(), Self> {
+                if !(true #(&& columns.#names.try_reserve())*) {
+                    return ::core::result::Result::Err(value);
+                }
+                #(
+                    // Safety: room was reserved just above
+                    unsafe { columns.#names.push_reserved(value.#names) };
+                )*
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
@@ -0,0 +1,41 @@
+use std::cell::Cell;
+
+// The number of objects of average size a bump should fit
+const OBJECTS_PER_CHUNK: usize = 64;
+// The smallest capacity of an adaptive bump
+const MIN_CAPACITY: usize = 4096;
+// The number of allocations after which the older ones weigh half as much,
+// so that the capacity follows the recent sizes of the objects
+const HALF_LIFE: usize = 1024;
+
+// The sizes of the objects allocated in a paving lately
+#[derive(Default)]
+pub(crate) struct AllocStats {
+    allocations: Cell<usize>,
+    bytes: Cell<usize>,
+    largest: Cell<usize>,
+}
+
+impl AllocStats {
+    pub(crate) fn record(&self, size: usize) {
+        if self.allocations.get() >= 2 * HALF_LIFE {
+            self.allocations.set(self.allocations.get() / 2);
+            self.bytes.set(self.bytes.get() / 2);
+            self.largest.set(self.largest.get() / 2);
+        }
+        self.allocations
+            .set(self.allocations.get().saturating_add(1));
+        self.bytes.set(self.bytes.get().saturating_add(size));
+        self.largest.set(self.largest.get().max(size));
+    }
+
+    // The capacity for the next bumps: large enough for many
+    // objects of average size, and for the largest recent one
+    pub(crate) fn capacity(&self) -> usize {
+        let average = self.bytes.get() / self.allocations.get().max(1);
+        average
+            .saturating_mul(OBJECTS_PER_CHUNK)
+            .max(self.largest.get().saturating_mul(2))
+            .max(MIN_CAPACITY)
+    }
+}
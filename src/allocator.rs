@@ -0,0 +1,133 @@
+use std::{alloc::Layout, mem::size_of, ptr::NonNull};
+
+use allocator_api2::alloc::{AllocError, Allocator};
+
+use crate::{metadata::Metadata, Bump, Paving};
+
+// Every allocated block holds a count of the bump, like a member,
+// so that the chunk outlives the collections using it.
+//
+// Safety:
+// - blocks are claimed in the bump, and stay valid until deallocated
+//   as they hold a count of it
+// - clones of a Bump allocate in the same chunk
+unsafe impl Allocator for Bump {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let start = self.claim(layout).ok_or(AllocError)?;
+        Ok(NonNull::slice_from_raw_parts(start, layout.size()))
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        // Safety: the block is in bounds of the bump
+        let end = NonNull::new_unchecked(ptr.as_ptr().add(layout.size()));
+        let metadata = self.metadata();
+        // Safety: metadata is valid as long as self lives
+        metadata.as_ref().try_rewind(ptr, end);
+        // Safety:
+        // the block held a count of the bump, and no other reference
+        // to metadata currently exists (only pointers)
+        Metadata::decrement_and_drop(metadata);
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        // Safety: the block is in bounds of the bump
+        let end = NonNull::new_unchecked(ptr.as_ptr().add(old_layout.size()));
+        let additional = new_layout.size() - old_layout.size();
+        // The last block of the bump can grow in place
+        if ptr.as_ptr().align_offset(new_layout.align()) == 0
+            && self.metadata().as_ref().try_extend(end, additional)
+        {
+            return Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()));
+        }
+        let new = self.allocate(new_layout)?;
+        // Safety: both blocks are valid for old_layout, and distinct
+        std::ptr::copy_nonoverlapping(ptr.as_ptr(), new.cast::<u8>().as_ptr(), old_layout.size());
+        // Safety: the block was allocated with old_layout, and moved
+        self.deallocate(ptr, old_layout);
+        Ok(new)
+    }
+}
+
+// The layout of a block preceded by the metadata of the bump it lives
+// in, and the offset of the block.
+// The metadata is stored right before the block, which keeps it aligned.
+fn with_header(layout: Layout) -> Result<(Layout, usize), AllocError> {
+    Layout::new::<NonNull<Metadata>>()
+        .extend(layout)
+        .map_err(|_| AllocError)
+}
+
+// # Safety
+// ptr must have been allocated by a Paving
+unsafe fn header(ptr: NonNull<u8>) -> NonNull<NonNull<Metadata>> {
+    NonNull::new_unchecked(ptr.as_ptr().sub(size_of::<NonNull<Metadata>>())).cast()
+}
+
+// Blocks spill into new bumps like other allocations of the paving, and
+// hold a count of their bump, whose metadata is stored in front of them.
+//
+// Safety:
+// - blocks are claimed in bumps of the paving, and stay valid until
+//   deallocated as they hold a count of them
+unsafe impl Allocator for Paving {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let (outer, offset) = with_header(layout)?;
+        self.try_alloc_inner(outer, (), |bump, ()| {
+            let start = bump.claim(outer).ok_or(())?;
+            // Safety: offset is within the claimed memory
+            #[allow(clippy::multiple_unsafe_ops_per_block)]
+            let ptr = unsafe { NonNull::new_unchecked(start.as_ptr().add(offset)) };
+            // Safety: the header is within the claimed memory, before ptr
+            #[allow(clippy::multiple_unsafe_ops_per_block)]
+            unsafe {
+                header(ptr).as_ptr().write(bump.metadata())
+            };
+            Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+        })
+        .map_err(|()| AllocError)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        let metadata = header(ptr).as_ptr().read();
+        // Safety: the block was allocated with layout, which is a valid header layout
+        let (_, offset) = with_header(layout).unwrap_unchecked();
+        // Safety: the block and its header are in bounds of the bump
+        let start = NonNull::new_unchecked(ptr.as_ptr().sub(offset));
+        // Safety: same as above
+        let end = NonNull::new_unchecked(ptr.as_ptr().add(layout.size()));
+        // Safety: the block holds a count of the bump
+        metadata.as_ref().try_rewind(start, end);
+        // Safety:
+        // the block held a count of the bump, and no other reference
+        // to metadata currently exists (only pointers)
+        Metadata::decrement_and_drop(metadata);
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        let metadata = header(ptr).as_ptr().read();
+        // Safety: the block is in bounds of the bump
+        let end = NonNull::new_unchecked(ptr.as_ptr().add(old_layout.size()));
+        let additional = new_layout.size() - old_layout.size();
+        // The last block of a bump can grow in place
+        if new_layout.align() <= old_layout.align() && metadata.as_ref().try_extend(end, additional)
+        {
+            return Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()));
+        }
+        let new = self.allocate(new_layout)?;
+        // Safety: both blocks are valid for old_layout, and distinct
+        std::ptr::copy_nonoverlapping(ptr.as_ptr(), new.cast::<u8>().as_ptr(), old_layout.size());
+        // Safety: the block was allocated with old_layout, and moved
+        self.deallocate(ptr, old_layout);
+        Ok(new)
+    }
+}
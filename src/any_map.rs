@@ -0,0 +1,81 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+};
+
+use crate::{BumpMember, NewBumpError, Paving};
+
+/// A map storing at most one value per type, all of them
+/// living in a single paving.
+pub struct BumpAnyMap {
+    paving: Paving,
+    values: HashMap<TypeId, BumpMember<dyn Any>>,
+}
+
+impl BumpAnyMap {
+    /// Try to create a new map, whose backing bumps will have the
+    /// corresponding capacity and align.
+    ///
+    /// See [`Bump::try_new`](`crate::Bump::try_new`).
+    pub fn try_new(capacity: usize, align: usize) -> Result<Self, NewBumpError> {
+        Ok(Self {
+            paving: Paving::try_new(capacity, align)?,
+            values: HashMap::new(),
+        })
+    }
+
+    /// Creates a new map, whose backing bumps will have the
+    /// corresponding capacity and align.
+    ///
+    /// See [`Bump::new`](`crate::Bump::new`).
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(capacity: usize, align: usize) -> Self {
+        Self::try_new(capacity, align).unwrap_or_else(|err| err.panic())
+    }
+
+    /// Try to insert a value, returning the previous value of the same type
+    /// if any.
+    ///
+    /// Fails if the value cannot be allocated in the paving.
+    pub fn try_insert<T: Any>(&mut self, value: T) -> Result<Option<T>, T> {
+        let member = self.paving.try_alloc(value)?.into_any();
+        let previous = self.values.insert(TypeId::of::<T>(), member);
+        Ok(previous
+            .and_then(|previous| previous.downcast::<T>().ok())
+            .map(BumpMember::take))
+    }
+
+    /// Returns the value of type `T`, if any.
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.values.get(&TypeId::of::<T>())?.downcast_ref()
+    }
+
+    /// Returns the value of type `T`, if any.
+    pub fn get_mut<T: Any>(&mut self) -> Option<&mut T> {
+        self.values.get_mut(&TypeId::of::<T>())?.downcast_mut()
+    }
+
+    /// Removes the value of type `T` from the map, returning it if any.
+    pub fn remove<T: Any>(&mut self) -> Option<T> {
+        let member = self.values.remove(&TypeId::of::<T>())?;
+        member.downcast::<T>().ok().map(BumpMember::take)
+    }
+
+    /// Whether the map holds a value of type `T`
+    pub fn contains<T: Any>(&self) -> bool {
+        self.values.contains_key(&TypeId::of::<T>())
+    }
+
+    /// The number of values in the map
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether the map holds no value
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
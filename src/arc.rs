@@ -0,0 +1,124 @@
+use std::{
+    alloc::Layout,
+    ops::Deref,
+    ptr::{addr_of_mut, drop_in_place, NonNull},
+    sync::atomic::{fence, AtomicUsize, Ordering},
+};
+
+use crate::{metadata::Metadata, Bump};
+
+struct ArcEntry<T> {
+    count: AtomicUsize,
+    value: T,
+}
+
+/// A pointer to a [`Bump`] offering thread-safe shared ownership of
+/// the pointed object, similar to [`std::sync::Arc`].
+///
+/// The object is dropped once all pointers are dropped, possibly
+/// on another thread than the one it was allocated on.
+/// See [`Bump::try_alloc_arc`].
+pub struct ArcBumpMember<T> {
+    metadata: NonNull<Metadata>,
+    entry: NonNull<ArcEntry<T>>,
+}
+
+// Safety:
+// Members only access the count of their bump, which is updated atomically
+// as the bump is shared before they are created, and the bump is only
+// deallocated from other threads if it belongs to no paving.
+unsafe impl<T: Send + Sync> Send for ArcBumpMember<T> {}
+
+// Safety: see Send
+unsafe impl<T: Send + Sync> Sync for ArcBumpMember<T> {}
+
+impl Bump {
+    /// Try to allocate an object with thread-safe shared ownership in the bump.
+    ///
+    /// Fails if there is not enough memory left, or if the bump was created
+    /// by a [`Paving`](crate::Paving) or a [`ChunkPool`](crate::ChunkPool),
+    /// whose bookkeeping cannot be updated from other threads.
+    pub fn try_alloc_arc<T>(&self, value: T) -> Result<ArcBumpMember<T>, T> {
+        // Safety: metadata is valid as long as self lives
+        let metadata = unsafe { self.metadata().as_ref() };
+        if !metadata.is_standalone() {
+            return Err(value);
+        }
+        let Some(start) = self.claim(ArcBumpMember::<T>::entry_layout()) else {
+            return Err(value);
+        };
+        metadata.share();
+        // Safety: start was just claimed for an entry
+        Ok(unsafe { ArcBumpMember::init(self.metadata(), start, value) })
+    }
+}
+
+impl<T> ArcBumpMember<T> {
+    // Writes a new entry holding value.
+    //
+    // # Safety
+    // - start must be valid for writes of an ArcEntry<T>, and aligned for it
+    // - the member takes over one count of metadata
+    pub(crate) unsafe fn init(metadata: NonNull<Metadata>, start: NonNull<u8>, value: T) -> Self {
+        let entry = start.cast::<ArcEntry<T>>();
+        entry.as_ptr().write(ArcEntry {
+            count: AtomicUsize::new(1),
+            value,
+        });
+        ArcBumpMember { metadata, entry }
+    }
+
+    // The layout of the memory init writes to
+    pub(crate) fn entry_layout() -> Layout {
+        Layout::new::<ArcEntry<T>>()
+    }
+}
+
+impl<T> ArcBumpMember<T> {
+    fn count(&self) -> &AtomicUsize {
+        // Safety:
+        // the entry is valid, and only its count is borrowed as
+        // the value may be being dropped
+        unsafe { &(*self.entry.as_ptr()).count }
+    }
+}
+
+impl<T> Deref for ArcBumpMember<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // Safety: self contains a valid entry
+        unsafe { &(*self.entry.as_ptr()).value }
+    }
+}
+
+impl<T> Clone for ArcBumpMember<T> {
+    fn clone(&self) -> Self {
+        self.count().fetch_add(1, Ordering::Relaxed);
+        Self {
+            metadata: self.metadata,
+            entry: self.entry,
+        }
+    }
+}
+
+impl<T> Drop for ArcBumpMember<T> {
+    fn drop(&mut self) {
+        // Same orderings as std::sync::Arc
+        if self.count().fetch_sub(1, Ordering::Release) != 1 {
+            return;
+        }
+        fence(Ordering::Acquire);
+        // Safety: the entry is valid, and its value is not used anymore
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        unsafe {
+            drop_in_place(addr_of_mut!((*self.entry.as_ptr()).value))
+        };
+        // The value is not wiped here, as the bump may be in use by
+        // another thread. It is when the bump is deallocated.
+        // Safety:
+        // No other reference to metadata currently exists
+        // (only pointers)
+        unsafe { Metadata::decrement_and_drop(self.metadata) };
+    }
+}
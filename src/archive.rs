@@ -0,0 +1,76 @@
+use rkyv::{
+    boxed::{ArchivedBox, BoxResolver},
+    rancor::{Fallible, Source},
+    rc::{ArchivedRc, RcFlavor, RcResolver},
+    ser::{Sharing, Writer},
+    Archive, ArchiveUnsized, Place, Serialize, SerializeUnsized,
+};
+
+use crate::{Bump, BumpBytes, BumpMember, Paving, RcBumpMember};
+
+// Members are archived like a Box and an Rc, so that archives can be
+// deserialized into them without an arena.
+
+impl<T: ArchiveUnsized + ?Sized> Archive for BumpMember<T> {
+    type Archived = ArchivedBox<T::Archived>;
+    type Resolver = BoxResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedBox::resolve_from_ref(&**self, resolver, out);
+    }
+}
+
+impl<T, S> Serialize<S> for BumpMember<T>
+where
+    T: SerializeUnsized<S> + ?Sized,
+    S: Fallible + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedBox::serialize_from_ref(&**self, serializer)
+    }
+}
+
+impl<T: ArchiveUnsized + ?Sized> Archive for RcBumpMember<T> {
+    type Archived = ArchivedRc<T::Archived, RcFlavor>;
+    type Resolver = RcResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedRc::resolve_from_ref(&**self, resolver, out);
+    }
+}
+
+// Members sharing a value are archived once, as for Rc
+impl<T, S> Serialize<S> for RcBumpMember<T>
+where
+    T: SerializeUnsized<S> + ?Sized,
+    S: Fallible + Writer + Sharing + ?Sized,
+    S::Error: Source,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedRc::<T::Archived, RcFlavor>::serialize_from_ref(&**self, serializer)
+    }
+}
+
+// The alignment of rkyv::util::AlignedVec, which archives expect
+const ARCHIVE_ALIGN: usize = 16;
+
+impl Bump {
+    /// Try to copy an archive into the bump, aligned so that it can be
+    /// validated and accessed in place with `rkyv::access`.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_archive(&self, bytes: &[u8]) -> Option<BumpBytes> {
+        self.try_alloc_bump_bytes_aligned(bytes, ARCHIVE_ALIGN)
+    }
+}
+
+impl Paving {
+    /// Try to copy an archive into the paving, aligned so that it can be
+    /// validated and accessed in place with `rkyv::access`.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the archive
+    pub fn try_alloc_archive(&self, bytes: &[u8]) -> Option<BumpBytes> {
+        self.try_alloc_bump_bytes_aligned(bytes, ARCHIVE_ALIGN)
+    }
+}
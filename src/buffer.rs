@@ -0,0 +1,305 @@
+use std::{
+    alloc::Layout,
+    ops::Deref,
+    ptr::{self, NonNull},
+    slice::SliceIndex,
+};
+
+use crate::{member::Sealed, metadata::Metadata, Bump, Paving};
+
+/// Bytes living in a [`Bump`], shared between this handle and
+/// every view created from it.
+///
+/// Views of subranges are handed out without copying, each keeping
+/// the bump alive.
+pub struct BumpBytes {
+    metadata: NonNull<Metadata>,
+    data: NonNull<[u8]>,
+}
+
+impl BumpBytes {
+    /// Returns a view of a subrange of these bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids",
+        allow(clippy::indexing_slicing, deprecated)
+    )]
+    pub fn slice<R: SliceIndex<[u8], Output = [u8]>>(&self, range: R) -> BumpBytes {
+        self.slice_ref(&self[range])
+    }
+
+    /// Returns a view of a subrange of these bytes, or None if the range
+    /// is out of bounds.
+    pub fn get<R: SliceIndex<[u8], Output = [u8]>>(&self, range: R) -> Option<BumpBytes> {
+        self.as_ref()
+            .get(range)
+            .and_then(|sub| self.try_slice_ref(sub))
+    }
+
+    /// Returns a view of `sub`, which must be a subslice of these bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sub` is not contained in these bytes.
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids",
+        allow(clippy::expect_used)
+    )]
+    pub fn slice_ref(&self, sub: &[u8]) -> BumpBytes {
+        self.try_slice_ref(sub)
+            .expect("Trying to view bytes not part of this buffer")
+    }
+
+    /// Returns a view of `sub`, or None if it is not a subslice of these bytes.
+    pub fn try_slice_ref(&self, sub: &[u8]) -> Option<BumpBytes> {
+        let outer = self.as_ptr_range();
+        let inner = sub.as_ptr_range();
+        if inner.start < outer.start || outer.end < inner.end {
+            return None;
+        }
+        // Safety:
+        // - metadata is valid
+        unsafe { Metadata::increment(self.metadata) };
+        Some(BumpBytes {
+            metadata: self.metadata,
+            data: NonNull::from(sub),
+        })
+    }
+
+    /// Try to append bytes at the end of these, growing them in place.
+    ///
+    /// Fails if these bytes do not end at the most recent allocation of
+    /// their bump, or if the bump is full. Other views are not affected.
+    pub fn try_push_bytes<'b>(&mut self, bytes: &'b [u8]) -> Result<(), &'b [u8]> {
+        let len = self.data.len();
+        // Safety: metadata is valid as long as self lives
+        let metadata = unsafe { self.metadata.as_ref() };
+        // Views may come from references to a part of the bump only, so
+        // the pointer is rebuilt from the beginning of the bump.
+        let beg = metadata.beg.as_ptr();
+        let offset = self.data.cast::<u8>().as_ptr() as usize - beg as usize;
+        // Safety: the bytes are in the bump
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        let start = unsafe { NonNull::new_unchecked(beg.add(offset)) };
+        // Safety: the bytes are valid, so one past their end is in bounds
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        let end = unsafe { NonNull::new_unchecked(start.as_ptr().add(len)) };
+        if !metadata.try_extend(end, bytes.len()) {
+            return Err(bytes);
+        }
+        // Safety:
+        // room was just claimed for the bytes, past every existing
+        // allocation, so it cannot overlap with them
+        unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), end.as_ptr(), bytes.len()) };
+        self.data = NonNull::slice_from_raw_parts(start, len + bytes.len());
+        Ok(())
+    }
+}
+
+impl Deref for BumpBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        // # Safety:
+        // self.data is valid and never mutated
+        unsafe { self.data.as_ref() }
+    }
+}
+
+impl AsRef<[u8]> for BumpBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl Clone for BumpBytes {
+    fn clone(&self) -> Self {
+        // Safety:
+        // - metadata is valid
+        unsafe { Metadata::increment(self.metadata) };
+        BumpBytes {
+            metadata: self.metadata,
+            data: self.data,
+        }
+    }
+}
+
+impl Sealed for BumpBytes {
+    fn metadata(&self) -> NonNull<Metadata> {
+        self.metadata
+    }
+
+    #[cfg(feature = "provenance")]
+    fn data(&self) -> NonNull<u8> {
+        self.data.cast()
+    }
+}
+
+impl Drop for BumpBytes {
+    fn drop(&mut self) {
+        // Safety:
+        // No other reference to metadata currently exists
+        // (only pointers)
+        unsafe { Metadata::decrement_and_drop(self.metadata) };
+    }
+}
+
+/// A string living in a [`Bump`], shared between this handle and
+/// every view created from it.
+///
+/// This is to [`str`] what [`BumpBytes`] is to `[u8]`, and allows for instance
+/// tokenizers to hand out tokens which are views of a single buffer.
+#[derive(Clone)]
+pub struct BumpStr(BumpBytes);
+
+impl BumpStr {
+    /// Returns a view of a subrange of this string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or not on char boundaries.
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids",
+        allow(clippy::indexing_slicing, deprecated)
+    )]
+    pub fn slice<R: SliceIndex<str, Output = str>>(&self, range: R) -> BumpStr {
+        self.slice_ref(&self[range])
+    }
+
+    /// Returns a view of a subrange of this string, or None if the range
+    /// is out of bounds or not on char boundaries.
+    pub fn get<R: SliceIndex<str, Output = str>>(&self, range: R) -> Option<BumpStr> {
+        self.as_ref()
+            .get(range)
+            .and_then(|sub| self.try_slice_ref(sub))
+    }
+
+    /// Returns a view of `sub`, which must be a substring of this string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sub` is not contained in this string.
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids",
+        allow(deprecated)
+    )]
+    pub fn slice_ref(&self, sub: &str) -> BumpStr {
+        BumpStr(self.0.slice_ref(sub.as_bytes()))
+    }
+
+    /// Returns a view of `sub`, or None if it is not a substring of this string.
+    pub fn try_slice_ref(&self, sub: &str) -> Option<BumpStr> {
+        self.0.try_slice_ref(sub.as_bytes()).map(BumpStr)
+    }
+
+    /// Try to append a string at the end of this one, growing it in place.
+    ///
+    /// See [`BumpBytes::try_push_bytes`].
+    pub fn try_push_str<'s>(&mut self, s: &'s str) -> Result<(), &'s str> {
+        self.0.try_push_bytes(s.as_bytes()).map_err(|_| s)
+    }
+
+    /// Converts the string into its underlying bytes
+    pub fn into_bytes(self) -> BumpBytes {
+        self.0
+    }
+}
+
+impl Deref for BumpStr {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        // Safety:
+        // BumpStr are only built from valid str, and
+        // views are on char boundaries
+        unsafe { std::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+impl AsRef<str> for BumpStr {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl Sealed for BumpStr {
+    fn metadata(&self) -> NonNull<Metadata> {
+        self.0.metadata
+    }
+
+    #[cfg(feature = "provenance")]
+    fn data(&self) -> NonNull<u8> {
+        self.0.data.cast()
+    }
+}
+
+impl Bump {
+    /// Try to copy bytes into the bump, returning a shareable handle to them.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_bump_bytes(&self, bytes: &[u8]) -> Option<BumpBytes> {
+        self.try_alloc_bump_bytes_aligned(bytes, 1)
+    }
+
+    // Copies the bytes at an address multiple of align, a power of two
+    pub(crate) fn try_alloc_bump_bytes_aligned(
+        &self,
+        bytes: &[u8],
+        align: usize,
+    ) -> Option<BumpBytes> {
+        let start = self.claim(Layout::from_size_align(bytes.len(), align).ok()?)?;
+        // Safety:
+        // - start is valid for writes of bytes.len() bytes (see can_fit)
+        // - the bump memory cannot overlap with an existing slice
+        unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), start.as_ptr(), bytes.len()) };
+        Some(BumpBytes {
+            metadata: self.metadata(),
+            data: NonNull::slice_from_raw_parts(start, bytes.len()),
+        })
+    }
+
+    /// Try to copy a string into the bump, returning a shareable handle to it.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_bump_str(&self, s: &str) -> Option<BumpStr> {
+        self.try_alloc_bump_bytes(s.as_bytes()).map(BumpStr)
+    }
+}
+
+impl Paving {
+    /// Try to copy bytes into the paving, returning a shareable handle to them.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the bytes
+    pub fn try_alloc_bump_bytes(&self, bytes: &[u8]) -> Option<BumpBytes> {
+        self.try_alloc_bump_bytes_aligned(bytes, 1)
+    }
+
+    // Copies the bytes at an address multiple of align, a power of two
+    pub(crate) fn try_alloc_bump_bytes_aligned(
+        &self,
+        bytes: &[u8],
+        align: usize,
+    ) -> Option<BumpBytes> {
+        let layout = Layout::from_size_align(bytes.len(), align).ok()?;
+        self.try_alloc_inner(layout, (), |bump, ()| {
+            bump.try_alloc_bump_bytes_aligned(bytes, align).ok_or(())
+        })
+        .ok()
+    }
+
+    /// Try to copy a string into the paving, returning a shareable handle to it.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the string
+    pub fn try_alloc_bump_str(&self, s: &str) -> Option<BumpStr> {
+        self.try_alloc_bump_bytes(s.as_bytes()).map(BumpStr)
+    }
+}
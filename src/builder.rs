@@ -0,0 +1,138 @@
+use std::mem::align_of;
+
+use crate::{
+    paving::ChunkLimits, ChunkPool, GrowthStrategy, NewBumpError, Paving, RetirementPolicy,
+};
+
+// The capacity of the bumps of a paving built with the default settings
+const DEFAULT_CAPACITY: usize = 4096;
+
+/// Configures a [`Paving`] before creating it.
+///
+/// See [`Paving::builder`].
+#[derive(Clone)]
+pub struct PavingBuilder {
+    capacity: usize,
+    align: usize,
+    growth: GrowthStrategy,
+    retirement: RetirementPolicy,
+    limits: ChunkLimits,
+    recent_chunks: usize,
+    adaptive: bool,
+    pool: Option<ChunkPool>,
+}
+
+impl Paving {
+    /// Returns a builder to configure a new paving, whose bumps default
+    /// to a capacity of 4096 bytes aligned like a `usize`.
+    pub fn builder() -> PavingBuilder {
+        PavingBuilder {
+            capacity: DEFAULT_CAPACITY,
+            align: align_of::<usize>(),
+            growth: GrowthStrategy::Fixed,
+            retirement: RetirementPolicy::Always,
+            limits: ChunkLimits::default(),
+            recent_chunks: 0,
+            adaptive: false,
+            pool: None,
+        }
+    }
+}
+
+impl PavingBuilder {
+    /// Sets the capacity of the first bump of the paving
+    pub fn chunk_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Sets the alignment of the bumps of the paving
+    pub fn align(mut self, align: usize) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// See [`Paving::with_growth_strategy`].
+    pub fn growth_strategy(mut self, strategy: GrowthStrategy) -> Self {
+        self.growth = strategy;
+        self
+    }
+
+    /// See [`Paving::with_retirement_policy`].
+    pub fn retirement_policy(mut self, policy: RetirementPolicy) -> Self {
+        self.retirement = policy;
+        self
+    }
+
+    /// Lets the paving pick the capacity of its bumps after the first one,
+    /// which then need not be chosen by hand.
+    ///
+    /// See [`Paving::with_adaptive_sizing`].
+    pub fn adaptive_sizing(mut self) -> Self {
+        self.adaptive = true;
+        self
+    }
+
+    /// See [`Paving::with_recent_chunks`].
+    pub fn recent_chunks(mut self, n: usize) -> Self {
+        self.recent_chunks = n;
+        self
+    }
+
+    /// Makes allocations fail with
+    /// [`AllocErrorKind::LimitReached`](crate::AllocErrorKind::LimitReached)
+    /// once they would need more than `max` live chunks.
+    ///
+    /// The first bump of the paving is created regardless.
+    pub fn max_chunks(mut self, max: usize) -> Self {
+        self.limits.chunks = Some(max);
+        self
+    }
+
+    /// See [`Paving::with_memory_budget`].
+    ///
+    /// The first bump of the paving is created regardless.
+    pub fn max_bytes(mut self, max: usize) -> Self {
+        self.limits.bytes = Some(max);
+        self
+    }
+
+    /// Draws the bumps of the paving from the pool, whose capacity and
+    /// alignment then take precedence.
+    ///
+    /// See [`ChunkPool::try_paving`].
+    pub fn pool(mut self, pool: ChunkPool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Try to create the configured paving.
+    ///
+    /// See [`Paving::try_new`].
+    pub fn try_build(self) -> Result<Paving, NewBumpError> {
+        let paving = match self.pool {
+            Some(pool) => Paving::try_from_pool(pool)?,
+            None => Paving::try_new(self.capacity, self.align)?,
+        };
+        let paving = paving
+            .with_growth_strategy(self.growth)
+            .with_retirement_policy(self.retirement)
+            .with_recent_chunks(self.recent_chunks)
+            .with_chunk_limits(self.limits);
+        Ok(match self.adaptive {
+            true => paving.with_adaptive_sizing(),
+            false => paving,
+        })
+    }
+
+    /// Creates the configured paving.
+    ///
+    /// See [`Paving::new`].
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn build(self) -> Paving {
+        self.try_build().unwrap_or_else(|err| err.panic())
+    }
+}
@@ -1,47 +1,89 @@
 use std::{
-    alloc::{alloc, dealloc, Layout, LayoutError},
+    alloc::{alloc, Layout, LayoutError},
+    any::Any,
     cell::Cell,
-    marker::PhantomData,
-    mem::{align_of, needs_drop, size_of},
+    error::Error,
+    fmt,
+    mem::{align_of, needs_drop, size_of, ManuallyDrop, MaybeUninit},
     ops::{Deref, DerefMut},
-    ptr::{addr_of_mut, drop_in_place, NonNull},
+    ptr::{self, drop_in_place, NonNull},
+    rc::Rc,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
 };
 
-/// The metadata of a Bump
-struct Metadata {
-    /// The number of pointer keeping this bump alive
-    count: u64,
-    /// The beginning of the Bump containing this Metadata
-    beg: NonNull<u8>,
-    /// The Layout that was obtained from [`Bump::inner_layout`]
-    layout: Layout,
+use crate::{
+    chunks::ChunkLink,
+    member::Sealed,
+    metadata::{Cursor, Metadata},
+    pool::PoolInner,
+    project::Erased,
+    Paving,
+};
+
+/// The reason why a [`Bump`] could not be created
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NewBumpError {
+    /// The requested capacity was null
+    NullCapacity,
+    /// The capacity and alignment do not describe a valid layout
+    InvalidLayout,
+    /// The memory allocation failed
+    OutOfMemory,
 }
 
-impl Metadata {
-    // # Safety
-    // - sself must not be dangling
-    // - No live reference to sself pointee must exist
-    unsafe fn decrement_and_drop(mut sself: NonNull<Self>) {
-        sself.as_mut().count -= 1;
-        if sself.as_ref().count == 0 {
-            // It is ok to dealloc because nobody references this chunk
-            // anymore
-            dealloc(sself.as_ref().beg.as_ptr(), sself.as_ref().layout)
+impl fmt::Display for NewBumpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            NewBumpError::NullCapacity => "the capacity of the bump is null",
+            NewBumpError::InvalidLayout => "invalid capacity or alignment for a bump",
+            NewBumpError::OutOfMemory => "memory allocation failed",
+        })
+    }
+}
+
+impl Error for NewBumpError {}
+
+impl NewBumpError {
+    // Only called by the APIs deprecated by the panic_free feature
+    #[cfg_attr(feature = "panic_free", allow(clippy::panic))]
+    pub(crate) fn panic(self) -> ! {
+        match self {
+            NewBumpError::NullCapacity => panic!("Trying to create a Bump with null capacity"),
+            NewBumpError::InvalidLayout => panic!("Invalid capacity or alignment for a Bump"),
+            NewBumpError::OutOfMemory => panic!("Memory allocation failed"),
         }
     }
 }
 
 // A Bump is a single object in memory containing first the data, then the metadata.
-// Two pointers are kept: one constant to the Metadata (and hence right limit of
-// the data), and the other to the first byte of the right, non allocated part
-// of the data.
+// The Bump itself keeps a pointer to the Metadata, and one to the Cursor of the
+// region it allocates into. The main cursor is stored in the Metadata, which is
+// its right limit. Regions split off a bump store their cursor in the data, right
+// before the memory they cover.
 //
 //
 
 /// A zone of memory to allocate into.
+///
+/// Cloning a bump returns a new handle to the same zone of memory.
 pub struct Bump {
     metadata: NonNull<Metadata>,
-    first_free: Cell<NonNull<u8>>,
+    cursor: NonNull<Cursor>,
+}
+
+impl Clone for Bump {
+    fn clone(&self) -> Self {
+        // Safety: metadata is valid as long as self lives
+        unsafe { Metadata::increment(self.metadata) };
+        Bump {
+            metadata: self.metadata,
+            cursor: self.cursor,
+        }
+    }
 }
 
 impl Drop for Bump {
@@ -54,11 +96,14 @@ impl Drop for Bump {
 }
 
 impl Bump {
-    fn inner_layout(capacity: usize, align: usize) -> Result<(Layout, usize), LayoutError> {
+    pub(crate) fn inner_layout(
+        capacity: usize,
+        align: usize,
+    ) -> Result<(Layout, usize), LayoutError> {
         Layout::from_size_align(capacity, align)?.extend(Layout::new::<Metadata>())
     }
 
-    /// Create a new Bump.
+    /// Try to create a new Bump.
     ///
     /// # Arguments
     ///
@@ -66,56 +111,104 @@ impl Bump {
     ///
     /// alignment: an indicative alignment for the
     /// first object of the bump
-    pub fn new(capacity: usize, align: usize) -> Self {
+    pub fn try_new(capacity: usize, align: usize) -> Result<Self, NewBumpError> {
         if capacity == 0 {
-            panic!("Trying to create a Bump with null capacity")
+            return Err(NewBumpError::NullCapacity);
         }
 
-        let (layout, metadata_offset) = Self::inner_layout(capacity, align).unwrap();
+        let (layout, metadata_offset) =
+            Self::inner_layout(capacity, align).map_err(|_| NewBumpError::InvalidLayout)?;
         // # Safety:
         // layout has a non zero size
         let inner_ptr = unsafe { alloc(layout) };
-        if inner_ptr.is_null() {
-            panic!("Memory allocation failed")
-        }
-        let metadata_ptr = {
-            // # Safety:
-            // metadat_offset and inner_ptr result from the same Layout::extend call
-            let metadata_ptr = unsafe { inner_ptr.add(metadata_offset).cast::<Metadata>() };
-            // # Safety:
-            // metadata is not null
-            unsafe { NonNull::new_unchecked(metadata_ptr) }
-        };
-        // Safety: inner_ptr has been tested to be non zero
-        let first_free = unsafe { NonNull::new_unchecked(inner_ptr) };
+        let Some(inner_ptr) = NonNull::new(inner_ptr) else {
+            return Err(NewBumpError::OutOfMemory);
+        };
+        // Safety: inner_ptr was just allocated with layout
+        Ok(unsafe { Bump::init_chunk(inner_ptr, layout, metadata_offset, None) })
+    }
+
+    // Writes the metadata of a fresh bump into a chunk of memory.
+    //
+    // # Safety
+    // - inner_ptr must be valid for writes of layout, and allocated with it
+    //   by the global allocator
+    // - layout and metadata_offset must come from Bump::inner_layout
+    pub(crate) unsafe fn init_chunk(
+        inner_ptr: NonNull<u8>,
+        layout: Layout,
+        metadata_offset: usize,
+        pool: Option<Rc<PoolInner>>,
+    ) -> Bump {
+        // # Safety:
+        // metadat_offset and inner_ptr result from the same Layout::extend call
+        let metadata_ptr = unsafe { inner_ptr.as_ptr().add(metadata_offset).cast::<Metadata>() };
+        // # Safety:
+        // metadata is not null
+        let metadata_ptr = unsafe { NonNull::new_unchecked(metadata_ptr) };
         let metadata = Metadata {
-            count: 1,
-            beg: first_free,
+            count: AtomicU64::new(1),
+            shared: AtomicBool::new(false),
+            beg: inner_ptr,
             layout,
+            cursor: Cursor {
+                first_free: Cell::new(inner_ptr),
+                end: metadata_ptr.cast(),
+            },
+            link: ChunkLink::default(),
+            pool: Cell::new(pool),
+            #[cfg(feature = "provenance")]
+            id: crate::provenance::next_chunk_id(),
+            #[cfg(feature = "mlock")]
+            locked: Cell::new(false),
+            #[cfg(feature = "mprotect")]
+            protected_end: Cell::new(0),
+            #[cfg(feature = "zeroize")]
+            sensitive: Cell::new(false),
         };
         // Safety: metadata_ptr comes from Layout::extend in
         // inner_bump_layout and is valid to write Metadata to
         unsafe { metadata_ptr.as_ptr().write(metadata) }
-        Bump {
-            metadata: metadata_ptr,
-            first_free: Cell::new(first_free),
-        }
+        // Safety: metadata_ptr was just initialized
+        unsafe { Bump::from_metadata(metadata_ptr) }
+    }
+
+    /// Create a new Bump.
+    ///
+    /// See [`Bump::try_new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bump cannot be created.
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(capacity: usize, align: usize) -> Self {
+        Self::try_new(capacity, align).unwrap_or_else(|err| err.panic())
     }
 
     // Returns two pointers:
-    // - first one is valid to write T
+    // - first one is valid to write an object of the given layout
     // - second one will be the new first free
     // Both are in the same allocated object
-    fn can_fit<T>(&self) -> Option<(*mut T, NonNull<u8>)> {
-        let first_free: *mut u8 = self.first_free.get().as_ptr();
-        let align_offset: usize = first_free.align_offset(align_of::<T>());
+    // Zero-sized values never advance the first free byte.
+    pub(crate) fn can_fit(&self, layout: Layout) -> Option<(*mut u8, NonNull<u8>)> {
+        // Safety: the cursor is valid as long as self lives
+        let cursor = unsafe { self.cursor.as_ref() };
+        if layout.size() == 0 {
+            let start = self.zst_address(layout.align())?;
+            return Some((start.as_ptr(), cursor.first_free.get()));
+        }
+        let first_free: *mut u8 = cursor.first_free.get().as_ptr();
+        let align_offset: usize = first_free.align_offset(layout.align());
         let tentative_start: usize = (first_free as usize).checked_add(align_offset)?;
-        let tentative_end: usize = tentative_start.checked_add(size_of::<T>())?;
-        if tentative_end <= self.metadata.as_ptr() as usize {
+        let tentative_end: usize = tentative_start.checked_add(layout.size())?;
+        if tentative_end <= cursor.end.as_ptr() as usize {
             // Safety:
             // Because operations were done without overflow:
-            // tentative_end = first_free + align_offset + size_of<T>
-            // and tentative_and <= self.metadata
+            // tentative_end = first_free + align_offset + layout.size()
+            // and tentative_and <= cursor.end
             // implies:
             // -  Both pointers are in the same allocation
             // - Sum fits a usize
@@ -125,34 +218,127 @@ impl Bump {
             let beg = unsafe { first_free.add(align_offset) };
             // Safety: same as above
             #[allow(clippy::multiple_unsafe_ops_per_block)]
-            let end = unsafe { NonNull::new_unchecked(beg.add(size_of::<T>())) };
-            Some((beg.cast(), end))
+            let end = unsafe { NonNull::new_unchecked(beg.add(layout.size())) };
+            Some((beg, end))
         } else {
             None
         }
     }
 }
 
-struct RawBumpMember<T> {
-    metadata: NonNull<Metadata>,
-    data: NonNull<T>,
+pub(crate) struct RawBumpMember<T> {
+    pub(crate) metadata: NonNull<Metadata>,
+    pub(crate) data: NonNull<T>,
 }
 
 impl Bump {
-    fn try_alloc_inner<T>(&self, value: T) -> Result<RawBumpMember<T>, T> {
-        let (start, end): (*mut T, NonNull<u8>) = match self.can_fit::<T>() {
-            Some(res) => res,
+    pub(crate) fn metadata(&self) -> NonNull<Metadata> {
+        self.metadata
+    }
+
+    pub(crate) fn cursor(&self) -> NonNull<Cursor> {
+        self.cursor
+    }
+
+    /// The number of bytes of the bump, or of its region if it was
+    /// split off another bump.
+    ///
+    /// See [`Bump::split`].
+    pub fn capacity(&self) -> usize {
+        // Safety: the cursor is valid as long as self lives
+        let cursor = unsafe { self.cursor.as_ref() };
+        cursor.end.as_ptr() as usize - self.region_start() as usize
+    }
+
+    /// The number of bytes allocated so far, padding included
+    pub fn used(&self) -> usize {
+        // Safety: the cursor is valid as long as self lives
+        let cursor = unsafe { self.cursor.as_ref() };
+        cursor.first_free.get().as_ptr() as usize - self.region_start() as usize
+    }
+
+    /// The number of bytes left for allocations
+    pub fn remaining(&self) -> usize {
+        // Safety: the cursor is valid as long as self lives
+        let cursor = unsafe { self.cursor.as_ref() };
+        cursor.end.as_ptr() as usize - cursor.first_free.get().as_ptr() as usize
+    }
+
+    // The first byte of the region the bump allocates into
+    fn region_start(&self) -> *const u8 {
+        // Safety: metadata is valid as long as self lives
+        let metadata = unsafe { self.metadata.as_ref() };
+        if self.cursor == NonNull::from(&metadata.cursor) {
+            metadata.beg.as_ptr()
+        } else {
+            // Regions split off a bump start right after their cursor
+            self.cursor.as_ptr().wrapping_add(1).cast()
+        }
+    }
+
+    // # Safety
+    // - metadata must not be dangling
+    // - the handle takes over one count of the metadata
+    pub(crate) unsafe fn from_metadata(metadata: NonNull<Metadata>) -> Bump {
+        let cursor = NonNull::from(&metadata.as_ref().cursor);
+        Bump { metadata, cursor }
+    }
+
+    // # Safety
+    // - metadata must not be dangling
+    // - cursor must be initialized and live as long as the chunk
+    // - the handle takes over one count of the metadata
+    pub(crate) unsafe fn from_cursor(metadata: NonNull<Metadata>, cursor: NonNull<Cursor>) -> Bump {
+        Bump { metadata, cursor }
+    }
+
+    // Reserves room for an object of the given layout, and accounts
+    // for it in the count of the bump.
+    // The caller is responsible for initializing the returned pointer
+    // and handing it to a member which will decrement the count.
+    pub(crate) fn claim(&self, layout: Layout) -> Option<NonNull<u8>> {
+        let start = self.claim_uncounted(layout)?;
+        // Safety:
+        // - metadata is valid
+        unsafe { Metadata::increment(self.metadata) }
+        Some(start)
+    }
+
+    // Reserves room for an object of the given layout, without accounting
+    // for it in the count of the bump.
+    // The caller is responsible for keeping the bump alive while
+    // the room is used.
+    pub(crate) fn claim_uncounted(&self, layout: Layout) -> Option<NonNull<u8>> {
+        let (start, end) = self.can_fit(layout)?;
+        // Safety: the cursor is valid as long as self lives
+        unsafe { self.cursor.as_ref() }.first_free.set(end);
+        // Safety: start is non zero
+        Some(unsafe { NonNull::new_unchecked(start) })
+    }
+
+    // An address of the chunk aligned for zero-sized values, which can be
+    // handed out any number of times as no byte is ever accessed there.
+    // Fails if the chunk holds no such address.
+    fn zst_address(&self, align: usize) -> Option<NonNull<u8>> {
+        // Safety: metadata is valid as long as self lives
+        let beg = unsafe { self.metadata.as_ref() }.beg.as_ptr();
+        let offset = beg.align_offset(align);
+        if offset > self.metadata.as_ptr() as usize - beg as usize {
+            return None;
+        }
+        // Safety: beg + offset is within the chunk, up to its metadata
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        Some(unsafe { NonNull::new_unchecked(beg.add(offset)) })
+    }
+
+    pub(crate) fn try_alloc_inner<T>(&self, value: T) -> Result<RawBumpMember<T>, T> {
+        let start = match self.claim(Layout::new::<T>()) {
+            Some(start) => start.cast::<T>(),
             None => return Err(value),
         };
         // Safety:
         // - start is valid for writes (see can_fit)
-        unsafe { start.write(value) };
-        // Safety: start is non zero
-        let start = unsafe { NonNull::new_unchecked(start) };
-        // Safety:
-        // - metadata is valid for writes
-        unsafe { (*self.metadata.as_ptr()).count += 1 }
-        self.first_free.set(end);
+        unsafe { start.as_ptr().write(value) };
         let res = RawBumpMember {
             metadata: self.metadata,
             data: start,
@@ -161,16 +347,113 @@ impl Bump {
     }
 }
 
+/// A position in a [`Bump`], to rewind it to with [`Bump::reset_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    cursor: NonNull<Cursor>,
+    first_free: NonNull<u8>,
+}
+
+impl Bump {
+    /// Returns the current position of the bump, to give back the memory
+    /// allocated after it with [`Bump::reset_to`], for instance when a
+    /// speculative parse fails.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            cursor: self.cursor,
+            // Safety: the cursor is valid as long as self lives
+            first_free: unsafe { self.cursor.as_ref() }.first_free.get(),
+        }
+    }
+
+    /// Gives back the memory allocated since the checkpoint, to be reused
+    /// by the next allocations.
+    ///
+    /// Fails if the checkpoint was taken on another bump or region, if the
+    /// bump was already rewound before it, or if the memory to give back
+    /// is read-only.
+    ///
+    /// # Safety
+    ///
+    /// No value allocated in the bump after the checkpoint may be used
+    /// anymore, by any handle to the bump.
+    pub unsafe fn reset_to(&self, checkpoint: Checkpoint) -> bool {
+        let cursor = self.cursor.as_ref();
+        if checkpoint.cursor != self.cursor || checkpoint.first_free > cursor.first_free.get() {
+            return false;
+        }
+        // Read-only memory cannot be handed out again
+        #[cfg(feature = "mprotect")]
+        if (checkpoint.first_free.as_ptr() as usize) < self.metadata.as_ref().protected_end.get() {
+            return false;
+        }
+        cursor.first_free.set(checkpoint.first_free);
+        true
+    }
+}
+
 /// A pointer to a [`Bump`] owning the underlying object,
 /// like a Box.
 ///
 /// The obejct will be dropped when the pointer is dropped.
-pub struct BumpMember<T> {
+pub struct BumpMember<T: ?Sized> {
     metadata: NonNull<Metadata>,
     data: NonNull<T>,
 }
 
-impl<T> Deref for BumpMember<T> {
+#[cfg(feature = "raw")]
+impl<T: ?Sized> BumpMember<T> {
+    /// Consumes the member, returning a pointer to its value, like
+    /// [`Box::into_raw`]. The value and its bump are kept alive until
+    /// a member is rebuilt with [`BumpMember::from_raw`].
+    pub fn into_raw(this: Self) -> *mut T {
+        let this = ManuallyDrop::new(this);
+        crate::raw::register(this.metadata);
+        this.data.as_ptr()
+    }
+
+    /// Rebuilds a member from a pointer returned by [`BumpMember::into_raw`],
+    /// finding the bump of the value from its address.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must come from [`BumpMember::into_raw`] on a member to the
+    /// same type, on the same thread, and be rebuilt only once.
+    pub unsafe fn from_raw(ptr: *mut T) -> Self {
+        BumpMember {
+            // Safety: the member kept the bump of the value alive
+            metadata: crate::raw::take_chunk_of(ptr.cast()).unwrap_unchecked(),
+            data: NonNull::new_unchecked(ptr),
+        }
+    }
+}
+
+impl<T: ?Sized> BumpMember<T> {
+    /// Returns a pointer to the value, for instance to tell members apart.
+    ///
+    /// This is an associated function, so as not to shadow methods of `T`
+    /// such as `<[T]>::as_ptr`.
+    pub fn as_ptr(this: &Self) -> *const T {
+        this.data.as_ptr()
+    }
+
+    /// Consumes the member, returning a reference to its value for the rest
+    /// of the program, like [`Box::leak`].
+    ///
+    /// The value is never dropped, and its bump is never deallocated.
+    pub fn leak<'a>(this: Self) -> &'a mut T
+    where
+        T: 'a,
+    {
+        let mut this = ManuallyDrop::new(this);
+        // Safety:
+        // the count held by the member is never released, so the
+        // value lives forever and is only reachable from here
+        unsafe { this.data.as_mut() }
+    }
+}
+
+impl<T: ?Sized> Deref for BumpMember<T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
@@ -181,7 +464,7 @@ impl<T> Deref for BumpMember<T> {
     }
 }
 
-impl<T> DerefMut for BumpMember<T> {
+impl<T: ?Sized> DerefMut for BumpMember<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         // # Safety:
         // self.data is aligned, valid,
@@ -191,8 +474,294 @@ impl<T> DerefMut for BumpMember<T> {
     }
 }
 
-impl<T> Drop for BumpMember<T> {
+impl<T> BumpMember<T> {
+    /// Moves the value out of the bump, releasing this member's
+    /// hold on it.
+    ///
+    /// If the value was the last allocation of its bump, its memory
+    /// can be reused by the next allocations.
+    pub fn take(self) -> T {
+        let this = ManuallyDrop::new(self);
+        // Safety:
+        // this.data is valid and owned by this member, which
+        // will not be dropped, so the value is read only once
+        let value = unsafe { this.data.as_ptr().read() };
+        // Safety: the value was moved out
+        #[cfg(feature = "zeroize")]
+        unsafe {
+            crate::sensitive::wipe_value(this.metadata, this.data)
+        };
+        // Safety: metadata is valid as long as the member lives
+        let metadata = unsafe { this.metadata.as_ref() };
+        let start = this.data.cast::<u8>();
+        // Safety: the value is in bounds, so one past its end is too
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        let end = unsafe { NonNull::new_unchecked(start.as_ptr().add(size_of::<T>())) };
+        metadata.try_rewind(start, end);
+        // Safety:
+        // No other reference to metadata currently exists
+        // (only pointers)
+        unsafe { Metadata::decrement_and_drop(this.metadata) };
+        value
+    }
+
+    /// Replaces the value, returning the previous one.
+    pub fn replace(&mut self, value: T) -> T {
+        std::mem::replace(self, value)
+    }
+
+    /// Moves the value out of the bump into a [`Box`].
+    pub fn into_box(self) -> Box<T> {
+        Box::new(self.take())
+    }
+
+    /// Try to move the value into the given paving, releasing this
+    /// member's hold on its bump.
+    ///
+    /// No user code is run, the value is only copied.
+    ///
+    /// Fails, returning the member unchanged, if no bump big enough can
+    /// be created in the paving.
+    pub fn try_relocate_into(self, paving: &Paving) -> Result<BumpMember<T>, Self> {
+        paving.try_alloc_inner(Layout::new::<T>(), self, |bump, member| {
+            let Some(start) = bump.claim(Layout::new::<T>()) else {
+                return Err(member);
+            };
+            let data = start.cast::<T>();
+            // Safety: start is valid for writes (see can_fit)
+            unsafe { data.as_ptr().write(member.take()) };
+            Ok(BumpMember {
+                metadata: bump.metadata,
+                data,
+            })
+        })
+    }
+
+    /// Moves the value into the given paving.
+    ///
+    /// See [`BumpMember::try_relocate_into`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if no bump big enough can be created in the paving.
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids",
+        allow(clippy::panic)
+    )]
+    pub fn relocate_into(self, paving: &Paving) -> BumpMember<T> {
+        match self.try_relocate_into(paving) {
+            Ok(member) => member,
+            Err(_) => panic!("Could not relocate a member into the paving"),
+        }
+    }
+}
+
+impl<T> BumpMember<T> {
+    /// Try to convert the member into one with shared ownership.
+    ///
+    /// If `!T::needs_drop()`, the value is shared in place. Otherwise it
+    /// needs room for its counts, and is moved next to them in its bump,
+    /// which fails if the bump is full.
+    pub fn try_into_rc(self) -> Result<RcBumpMember<T>, Self> {
+        if !needs_drop::<T>() {
+            let this = ManuallyDrop::new(self);
+            // Safety:
+            // values which do not need drop have no header, and
+            // the count held by the member is handed over
+            return Ok(unsafe { RcBumpMember::init(this.metadata, this.data) });
+        }
+        let Some((layout, offset)) = RcHeader::layout(Layout::new::<T>(), true) else {
+            return Err(self);
+        };
+        let bump = Bump::of_member(&self);
+        let Some(start) = bump.claim(layout) else {
+            return Err(self);
+        };
+        // Safety: the value is at offset in the claimed room
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        let data = unsafe { NonNull::new_unchecked(start.as_ptr().add(offset)).cast::<T>() };
+        // Safety: data is valid for writes and aligned
+        unsafe { data.as_ptr().write(self.take()) };
+        // Safety:
+        // the room for the header was claimed before the value,
+        // and claim accounted for the member in the count
+        Ok(unsafe { RcBumpMember::init(bump.metadata, data) })
+    }
+
+    /// Converts the member into one with shared ownership.
+    ///
+    /// See [`BumpMember::try_into_rc`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value must be moved and its bump is full.
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids",
+        allow(clippy::panic)
+    )]
+    pub fn into_rc(self) -> RcBumpMember<T> {
+        match self.try_into_rc() {
+            Ok(member) => member,
+            Err(_) => panic!("No room left to share a member"),
+        }
+    }
+}
+
+impl<T: Any> BumpMember<T> {
+    /// Converts the member into a type-erased one.
+    ///
+    /// See [`BumpMember::downcast`].
+    pub fn into_any(self) -> BumpMember<dyn Any> {
+        let this = ManuallyDrop::new(self);
+        BumpMember {
+            metadata: this.metadata,
+            data: this.data,
+        }
+    }
+}
+
+impl<T> BumpMember<T> {
+    // Forgets the type of the value, only remembering how to drop it
+    pub(crate) fn erase<'a>(self) -> BumpMember<dyn Erased + 'a>
+    where
+        T: 'a,
+    {
+        let this = ManuallyDrop::new(self);
+        BumpMember {
+            metadata: this.metadata,
+            data: this.data,
+        }
+    }
+}
+
+impl BumpMember<dyn Any> {
+    /// Try to convert a type-erased member back into a typed one,
+    /// failing if `T` is not the type of the pointee.
+    pub fn downcast<T: Any>(self) -> Result<BumpMember<T>, Self> {
+        if !self.is::<T>() {
+            return Err(self);
+        }
+        let this = ManuallyDrop::new(self);
+        Ok(BumpMember {
+            metadata: this.metadata,
+            data: this.data.cast(),
+        })
+    }
+}
+
+impl<T, const N: usize> BumpMember<[T; N]> {
+    /// Converts a member to an array into a member to a slice.
+    pub fn into_slice(self) -> BumpMember<[T]> {
+        let this = ManuallyDrop::new(self);
+        BumpMember {
+            metadata: this.metadata,
+            data: this.data,
+        }
+    }
+}
+
+impl<T> BumpMember<MaybeUninit<T>> {
+    /// Converts a member to an initialized value, like
+    /// [`MaybeUninit::assume_init`].
+    ///
+    /// # Safety
+    ///
+    /// The value must have been fully initialized.
+    pub unsafe fn assume_init(self) -> BumpMember<T> {
+        let this = ManuallyDrop::new(self);
+        BumpMember {
+            metadata: this.metadata,
+            data: this.data.cast(),
+        }
+    }
+}
+
+impl<T> BumpMember<[MaybeUninit<T>]> {
+    /// Converts a member to a slice of initialized values, see
+    /// [`BumpMember::assume_init`].
+    ///
+    /// # Safety
+    ///
+    /// Every element must have been fully initialized.
+    pub unsafe fn assume_init_slice(self) -> BumpMember<[T]> {
+        let this = ManuallyDrop::new(self);
+        let len = this.data.len();
+        BumpMember {
+            metadata: this.metadata,
+            data: NonNull::slice_from_raw_parts(this.data.cast(), len),
+        }
+    }
+}
+
+impl<T> BumpMember<[T]> {
+    // Splits the member into one member per element of the slice
+    pub(crate) fn into_members(self) -> Vec<BumpMember<T>> {
+        let len = self.data.len();
+        if len == 0 {
+            return Vec::new();
+        }
+        let this = ManuallyDrop::new(self);
+        let start = this.data.cast::<T>();
+        // Safety: metadata is valid, and every element now holds a count
+        unsafe { Metadata::increment_by(this.metadata, len as u64 - 1) };
+        (0..len)
+            .map(|i| BumpMember {
+                metadata: this.metadata,
+                // Safety: i is within the slice
+                #[allow(clippy::multiple_unsafe_ops_per_block)]
+                data: unsafe { NonNull::new_unchecked(start.as_ptr().add(i)) },
+            })
+            .collect()
+    }
+
+    /// Try to append a value at the end of the slice, growing it in place.
+    ///
+    /// Fails if the slice is not the most recent allocation of its bump,
+    /// or if the bump is full.
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        let len = self.data.len();
+        let start = self.data.cast::<T>();
+        // Safety: the slice is valid, so one past its end is in bounds
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        let end = unsafe { NonNull::new_unchecked(start.as_ptr().add(len)) };
+        // Safety: metadata is valid as long as self lives
+        let metadata = unsafe { self.metadata.as_ref() };
+        if !metadata.try_extend(end.cast(), size_of::<T>()) {
+            return Err(value);
+        }
+        // Safety: room was just claimed for the value, and is aligned
+        // as it follows the other elements of the slice
+        unsafe { end.as_ptr().write(value) };
+        self.data = NonNull::slice_from_raw_parts(start, len + 1);
+        Ok(())
+    }
+}
+
+impl<T: ?Sized> BumpMember<T> {
+    /// Returns a handle to the bump this member lives in,
+    /// to allocate objects next to it.
+    pub fn arena(&self) -> Bump {
+        Bump::of_member(self)
+    }
+}
+
+impl<T: ?Sized> Sealed for BumpMember<T> {
+    fn metadata(&self) -> NonNull<Metadata> {
+        self.metadata
+    }
+
+    #[cfg(feature = "provenance")]
+    fn data(&self) -> NonNull<u8> {
+        self.data.cast()
+    }
+}
+
+impl<T: ?Sized> Drop for BumpMember<T> {
     fn drop(&mut self) {
+        #[cfg(feature = "zeroize")]
+        let size = std::mem::size_of_val::<T>(self);
         // Safety:
         // We are the only access to BumpMember
         // which owns the T
@@ -201,6 +770,11 @@ impl<T> Drop for BumpMember<T> {
         unsafe {
             drop_in_place(self.data.as_ptr());
         }
+        // Safety: the value was dropped
+        #[cfg(feature = "zeroize")]
+        unsafe {
+            crate::sensitive::wipe_bytes(self.metadata, self.data.cast(), size)
+        };
         // Safety:
         // No other reference to metadata currently exists
         // (only pointers)
@@ -218,28 +792,492 @@ impl Bump {
         let RawBumpMember { metadata, data } = self.try_alloc_inner(value)?;
         Ok(BumpMember { metadata, data })
     }
+
+    /// Try to allocate an object at an address multiple of `align`, for
+    /// instance to give it its own cache line, whatever the alignment of
+    /// the bump.
+    ///
+    /// Fails if `align` is not a power of two, or if there is not enough
+    /// memory left
+    pub fn try_alloc_aligned<T>(&self, value: T, align: usize) -> Result<BumpMember<T>, T> {
+        let Ok(layout) = Layout::new::<T>().align_to(align) else {
+            return Err(value);
+        };
+        let Some(start) = self.claim(layout) else {
+            return Err(value);
+        };
+        let data = start.cast::<T>();
+        // Safety: data was just claimed for a T, so it is valid for writes
+        unsafe { data.as_ptr().write(value) };
+        Ok(BumpMember {
+            metadata: self.metadata,
+            data,
+        })
+    }
+
+    /// Try to allocate an object built by a closure, reserving its room
+    /// first so that it can be built in place.
+    ///
+    /// If the closure panics, the room is given back.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_with<T, F: FnOnce() -> T>(&self, build: F) -> Result<BumpMember<T>, F> {
+        let Some(reservation) = Reservation::claim(self, Layout::new::<T>()) else {
+            return Err(build);
+        };
+        let data = reservation.start.cast::<T>();
+        // Safety: data is valid for writes and aligned
+        unsafe { data.as_ptr().write(build()) };
+        reservation.keep();
+        Ok(BumpMember {
+            metadata: self.metadata,
+            data,
+        })
+    }
+
+    /// Try to allocate room for an object, to be initialized in place,
+    /// for instance field by field.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_uninit<T>(&self) -> Option<BumpMember<MaybeUninit<T>>> {
+        let reservation = Reservation::claim(self, Layout::new::<T>())?;
+        let data = reservation.start.cast();
+        reservation.keep();
+        Some(BumpMember {
+            metadata: self.metadata,
+            data,
+        })
+    }
+
+    /// Try to allocate an object built by a fallible closure, in place.
+    ///
+    /// If the closure fails or panics, the room is given back, and the error
+    /// is returned in the inner result.
+    ///
+    /// Fails if there is not enough memory left, giving back the closure.
+    pub fn try_alloc_try_with<T, E, F>(&self, build: F) -> Result<Result<BumpMember<T>, E>, F>
+    where
+        F: FnOnce() -> Result<T, E>,
+    {
+        let Some(reservation) = Reservation::claim(self, Layout::new::<T>()) else {
+            return Err(build);
+        };
+        let value = match build() {
+            Ok(value) => value,
+            Err(err) => return Ok(Err(err)),
+        };
+        let data = reservation.start.cast::<T>();
+        // Safety: data is valid for writes and aligned
+        unsafe { data.as_ptr().write(value) };
+        reservation.keep();
+        Ok(Ok(BumpMember {
+            metadata: self.metadata,
+            data,
+        }))
+    }
+
+    /// Checks that `count` more objects of type `T` fit in the bump,
+    /// so that they can be allocated with [`Bump::alloc_unchecked`].
+    pub fn try_reserve<T>(&self, count: usize) -> bool {
+        Layout::array::<T>(count).is_ok_and(|layout| self.can_fit(layout).is_some())
+    }
+
+    /// Allocates an object without checking that it fits, for hot loops.
+    ///
+    /// Unlike with members, the count of the bump is not incremented: the
+    /// object is borrowed from this handle, which keeps the bump alive,
+    /// and it is never dropped.
+    ///
+    /// # Safety
+    ///
+    /// [`Bump::try_reserve`] must have returned true for this type and
+    /// at least as many objects as allocated since, by any handle to
+    /// this bump.
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn alloc_unchecked<T>(&self, value: T) -> &mut T {
+        if size_of::<T>() == 0 {
+            // Safety: the address is aligned and never read
+            let start = self
+                .zst_address(align_of::<T>())
+                .unwrap_unchecked()
+                .cast::<T>();
+            start.as_ptr().write(value);
+            return &mut *start.as_ptr();
+        }
+        // Safety: the cursor is valid as long as self lives
+        let cursor = self.cursor.as_ref();
+        let first_free = cursor.first_free.get().as_ptr();
+        // Safety: the room was reserved, alignment included
+        let start = first_free
+            .add(first_free.align_offset(align_of::<T>()))
+            .cast::<T>();
+        cursor
+            .first_free
+            .set(NonNull::new_unchecked(start.add(1).cast()));
+        // Safety: start is valid for writes and was never handed out
+        start.write(value);
+        &mut *start
+    }
+
+    /// Try to grow the allocation ending at `end` by `additional` bytes,
+    /// in place.
+    ///
+    /// Fails if it is not the last allocation of the bump, or if there is
+    /// not enough memory left
+    pub fn try_extend_last(&self, end: NonNull<u8>, additional: usize) -> bool {
+        // Safety: metadata is valid as long as self lives
+        unsafe { self.metadata.as_ref() }.try_extend(end, additional)
+    }
+
+    /// Try to grow or shrink the allocation of `old_size` bytes at `ptr` to
+    /// `new_size` bytes, in place. The room given back by shrinking is
+    /// reused by the next allocations.
+    ///
+    /// Fails if it is not the last allocation of the bump, or if there is
+    /// not enough memory left
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to `old_size` bytes allocated in this bump, and
+    /// the bytes past `new_size` must not be used anymore.
+    pub unsafe fn try_realloc_last(
+        &self,
+        ptr: NonNull<u8>,
+        old_size: usize,
+        new_size: usize,
+    ) -> bool {
+        self.metadata.as_ref().try_resize(ptr, old_size, new_size)
+    }
+
+    /// Try to copy a slice into the bump, with a single copy.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_slice_copy<T: Copy>(&self, src: &[T]) -> Option<BumpMember<[T]>> {
+        self.try_alloc_slice_copy_aligned(src, align_of::<T>())
+    }
+
+    /// Try to copy a slice into the bump, at an address multiple of `align`,
+    /// whatever the alignment of the bump.
+    ///
+    /// Fails if `align` is not a power of two, or if there is not enough
+    /// memory left
+    pub fn try_alloc_slice_copy_aligned<T: Copy>(
+        &self,
+        src: &[T],
+        align: usize,
+    ) -> Option<BumpMember<[T]>> {
+        let layout = Layout::for_value(src).align_to(align).ok()?;
+        let start = self.claim(layout)?.cast::<T>();
+        // Safety:
+        // start is valid for writes of src (see can_fit), and freshly
+        // claimed so it cannot overlap with it
+        unsafe { ptr::copy_nonoverlapping(src.as_ptr(), start.as_ptr(), src.len()) };
+        Some(BumpMember {
+            metadata: self.metadata,
+            data: NonNull::slice_from_raw_parts(start, src.len()),
+        })
+    }
+
+    /// Try to allocate room for a slice of `len` elements, to be
+    /// initialized in place, for instance while reading from a stream.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_slice_uninit<T>(&self, len: usize) -> Option<BumpMember<[MaybeUninit<T>]>> {
+        let start = self.claim(Layout::array::<T>(len).ok()?)?;
+        Some(BumpMember {
+            metadata: self.metadata,
+            data: NonNull::slice_from_raw_parts(start.cast(), len),
+        })
+    }
+
+    /// Try to clone the elements of a slice into the bump.
+    ///
+    /// If cloning an element panics, the elements already cloned are dropped.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_slice_clone<T: Clone>(&self, src: &[T]) -> Option<BumpMember<[T]>> {
+        let mut src_iter = src.iter();
+        self.try_fill_slice(src.len(), || src_iter.next().cloned())
+            .ok()
+    }
+
+    /// Try to allocate a slice of `len` elements, each produced by calling
+    /// `f` with its index.
+    ///
+    /// If `f` panics, the elements already produced are dropped.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_slice_fill_with<T>(
+        &self,
+        len: usize,
+        mut f: impl FnMut(usize) -> T,
+    ) -> Option<BumpMember<[T]>> {
+        let mut index = 0;
+        self.try_fill_slice(len, move || {
+            let value = f(index);
+            index += 1;
+            Some(value)
+        })
+        .ok()
+    }
+
+    /// Try to allocate a slice of `len` default elements.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_slice_fill_default<T: Default>(&self, len: usize) -> Option<BumpMember<[T]>> {
+        self.try_alloc_slice_fill_with(len, |_| T::default())
+    }
+
+    /// Try to allocate a slice of `len` copies of `value`.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_slice_fill_copy<T: Copy>(
+        &self,
+        len: usize,
+        value: T,
+    ) -> Option<BumpMember<[T]>> {
+        self.try_alloc_slice_fill_with(len, |_| value)
+    }
+
+    /// Try to allocate a slice holding the values of an iterator, written in
+    /// place in room reserved for its announced length.
+    ///
+    /// If the iterator yields fewer values than announced, the slice is
+    /// shorter and the room left is given back, and values past the announced
+    /// length are ignored. If it panics, the values already written are dropped.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_from_iter<I>(&self, values: I) -> Option<BumpMember<[I::Item]>>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut values = values.into_iter();
+        self.try_fill_slice(values.len(), move || values.next())
+            .ok()
+    }
+
+    // Allocates a slice of at most len elements produced by next, stopping
+    // early if it returns None. Gives next back if there is no room.
+    pub(crate) fn try_fill_slice<T, F: FnMut() -> Option<T>>(
+        &self,
+        len: usize,
+        mut next: F,
+    ) -> Result<BumpMember<[T]>, F> {
+        let Ok(layout) = Layout::array::<T>(len) else {
+            return Err(next);
+        };
+        let Some(start) = self.claim(layout) else {
+            return Err(next);
+        };
+        let mut writer = SliceWriter::<T> {
+            metadata: self.metadata,
+            start: start.cast(),
+            len: 0,
+            capacity: len,
+        };
+        while writer.len < len {
+            let Some(value) = next() else {
+                break;
+            };
+            // Safety: the claimed room has capacity elements
+            #[allow(clippy::multiple_unsafe_ops_per_block)]
+            unsafe {
+                writer.start.as_ptr().add(writer.len).write(value)
+            };
+            writer.len += 1;
+        }
+        Ok(writer.finish())
+    }
+
+    /// Try to copy a string into the bump.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_str(&self, s: &str) -> Option<BumpMember<str>> {
+        let bytes = ManuallyDrop::new(self.try_alloc_slice_copy(s.as_bytes())?);
+        Some(BumpMember {
+            metadata: bytes.metadata,
+            // Safety: str has the same layout as [u8], and the bytes were copied from a str
+            data: unsafe { NonNull::new_unchecked(bytes.data.as_ptr() as *mut str) },
+        })
+    }
+}
+
+// Room claimed in a bump for a value being built.
+// Unless it is kept, the room is given back along with its count,
+// for instance if building the value panics.
+struct Reservation {
+    metadata: NonNull<Metadata>,
+    start: NonNull<u8>,
+    end: NonNull<u8>,
+}
+
+impl Reservation {
+    fn claim(bump: &Bump, layout: Layout) -> Option<Self> {
+        let start = bump.claim(layout)?;
+        // Safety: the claimed room is layout.size() bytes long
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        let end = unsafe { NonNull::new_unchecked(start.as_ptr().add(layout.size())) };
+        Some(Reservation {
+            metadata: bump.metadata,
+            start,
+            end,
+        })
+    }
+
+    // Keeps the room, whose count is handed over to a member
+    fn keep(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        // Safety: the metadata is valid while the room holds a count
+        unsafe { self.metadata.as_ref() }.try_rewind(self.start, self.end);
+        // Safety:
+        // No other reference to metadata currently exists
+        // (only pointers)
+        unsafe { Metadata::decrement_and_drop(self.metadata) };
+    }
+}
+
+// Writes the elements of a slice being allocated in place.
+// If producing an element panics, the elements already written are
+// dropped and the room of the slice is given back.
+struct SliceWriter<T> {
+    metadata: NonNull<Metadata>,
+    start: NonNull<T>,
+    len: usize,
+    capacity: usize,
+}
+
+impl<T> SliceWriter<T> {
+    // Gives back the room past the first len elements, if possible.
+    fn rewind(&self, len: usize) {
+        // Safety: the metadata is valid while the slice holds a count
+        let metadata = unsafe { self.metadata.as_ref() };
+        // Safety: both pointers are within or one past the claimed room
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        unsafe {
+            let start = NonNull::new_unchecked(self.start.as_ptr().add(len).cast());
+            let end = NonNull::new_unchecked(self.start.as_ptr().add(self.capacity).cast());
+            metadata.try_rewind(start, end);
+        }
+    }
+
+    fn finish(self) -> BumpMember<[T]> {
+        let this = ManuallyDrop::new(self);
+        this.rewind(this.len);
+        BumpMember {
+            metadata: this.metadata,
+            data: NonNull::slice_from_raw_parts(this.start, this.len),
+        }
+    }
 }
 
-struct BumpRcEntry<T> {
-    count: usize,
-    value: T,
+impl<T> Drop for SliceWriter<T> {
+    fn drop(&mut self) {
+        let written = NonNull::slice_from_raw_parts(self.start, self.len);
+        // Safety: the first len elements were written, and are not used anymore
+        unsafe { drop_in_place(written.as_ptr()) };
+        // Safety: the elements were dropped
+        #[cfg(feature = "zeroize")]
+        unsafe {
+            crate::sensitive::wipe_bytes(
+                self.metadata,
+                self.start.cast(),
+                size_of::<T>() * self.len,
+            )
+        };
+        self.rewind(0);
+        // Safety:
+        // No other reference to metadata currently exists
+        // (only pointers)
+        unsafe { Metadata::decrement_and_drop(self.metadata) };
+    }
 }
 
-enum NeedsDrop<T> {
-    Yes(NonNull<BumpRcEntry<T>>),
-    No(NonNull<T>),
+// The counts of a value with shared ownership which needs to be dropped,
+// stored right before it so that the value may be unsized.
+pub(crate) struct RcHeader {
+    count: Cell<usize>,
+    // The number of weak members, plus one while count is not null
+    weak: Cell<usize>,
 }
 
-impl<T> NeedsDrop<T> {
-    fn from_rc_data(rc_data: NonNull<u8>) -> NeedsDrop<T> {
+impl RcHeader {
+    // The layout of a value with shared ownership, preceded by its header
+    // if it needs to be dropped, and the offset of the value in it.
+    pub(crate) fn layout(value: Layout, needs_drop: bool) -> Option<(Layout, usize)> {
+        if needs_drop {
+            Layout::new::<RcHeader>().extend(value).ok()
+        } else {
+            Some((value, 0))
+        }
+    }
+
+    // The layout of a value with shared ownership, preceded by its header
+    // if it needs to be dropped.
+    pub(crate) fn entry_layout(value: Layout, needs_drop: bool) -> Layout {
+        Self::layout(value, needs_drop).map_or(value, |(layout, _)| layout)
+    }
+
+    // The header of the value pointed to by data, if it needs to be dropped.
+    fn of<T: ?Sized>(data: NonNull<T>) -> Option<NonNull<RcHeader>> {
         if needs_drop::<T>() {
-            NeedsDrop::Yes(rc_data.cast())
+            // Safety:
+            // values needing drop are preceded by their header,
+            // so the header is in the same allocation
+            #[allow(clippy::multiple_unsafe_ops_per_block)]
+            Some(unsafe { NonNull::new_unchecked(data.cast::<RcHeader>().as_ptr().sub(1)) })
         } else {
-            NeedsDrop::No(rc_data.cast())
+            None
         }
     }
 }
 
+/// The capacity a [`Bump`] needs to hold `n` values of type `T`
+/// allocated with [`Bump::try_alloc`].
+///
+/// This accounts for the padding needed to align the first value,
+/// whatever the alignment of the bump. The metadata of the bump is
+/// stored past its capacity, and needs not be accounted for.
+pub const fn required_capacity_for<T>(n: usize) -> usize {
+    capacity_for(size_of::<T>(), align_of::<T>(), n)
+}
+
+/// The capacity a [`Bump`] needs to hold `n` values of type `T`
+/// allocated with [`Bump::try_alloc_rc`].
+///
+/// See [`required_capacity_for`].
+pub const fn required_capacity_for_rc<T>(n: usize) -> usize {
+    if needs_drop::<T>() {
+        // The value is preceded by its header
+        let align = if align_of::<T>() > align_of::<RcHeader>() {
+            align_of::<T>()
+        } else {
+            align_of::<RcHeader>()
+        };
+        let offset = round_up(size_of::<RcHeader>(), align_of::<T>());
+        capacity_for(round_up(offset + size_of::<T>(), align), align, n)
+    } else {
+        required_capacity_for::<T>(n)
+    }
+}
+
+const fn round_up(size: usize, align: usize) -> usize {
+    size.div_ceil(align) * align
+}
+
+const fn capacity_for(size: usize, align: usize, n: usize) -> usize {
+    if n == 0 {
+        0
+    } else {
+        size.saturating_mul(n).saturating_add(align - 1)
+    }
+}
+
 /// A pointer to a [`Bump`] offering shared ownership of
 /// the pointed object, similar to [`std::rc::Rc`].
 ///
@@ -247,15 +1285,35 @@ impl<T> NeedsDrop<T> {
 ///
 /// If `!T::needs_drop()`, most of the dropping code for
 /// the `T` itself is optimized away.
-pub struct RcBumpMember<T> {
+pub struct RcBumpMember<T: ?Sized> {
     metadata: NonNull<Metadata>,
-    rc_data: NonNull<u8>,
-    _marker: PhantomData<T>,
+    // The header of the value, if its type needed to be dropped when it
+    // was allocated. It is kept apart from data to survive type erasure.
+    header: Option<NonNull<RcHeader>>,
+    data: NonNull<T>,
 }
 
-impl<T> RcBumpMember<T> {
-    fn rc_data(&self) -> NeedsDrop<T> {
-        NeedsDrop::from_rc_data(self.rc_data)
+impl<T: ?Sized> RcBumpMember<T> {
+    // Shares the value pointed to by data, writing its header
+    // if it needs to be dropped.
+    //
+    // # Safety
+    // - data must point to a valid value in the bump of metadata
+    // - if T needs drop, room for a header must be right before data
+    // - the member takes over one count of the metadata
+    pub(crate) unsafe fn init(metadata: NonNull<Metadata>, data: NonNull<T>) -> Self {
+        let header = RcHeader::of(data);
+        if let Some(header) = header {
+            header.as_ptr().write(RcHeader {
+                count: Cell::new(1),
+                weak: Cell::new(1),
+            });
+        }
+        RcBumpMember {
+            metadata,
+            header,
+            data,
+        }
     }
 }
 
@@ -264,78 +1322,614 @@ impl Bump {
     ///
     /// Fails if there is not enough memory left
     pub fn try_alloc_rc<T>(&self, value: T) -> Result<RcBumpMember<T>, T> {
-        if needs_drop::<T>() {
-            let RawBumpMember { metadata, data } = self
-                .try_alloc_inner(BumpRcEntry { count: 1, value })
-                .map_err(|srce| srce.value)?;
-            Ok(RcBumpMember {
-                metadata,
-                rc_data: data.cast(),
-                _marker: PhantomData,
+        let Some((layout, offset)) = RcHeader::layout(Layout::new::<T>(), needs_drop::<T>()) else {
+            return Err(value);
+        };
+        let Some(start) = self.claim(layout) else {
+            return Err(value);
+        };
+        // Safety: the value is at offset in the claimed room
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        let data = unsafe { NonNull::new_unchecked(start.as_ptr().add(offset)).cast::<T>() };
+        // Safety: data is valid for writes and aligned
+        unsafe { data.as_ptr().write(value) };
+        // Safety:
+        // the room for the header was claimed before the value,
+        // and claim accounted for the member in the count
+        Ok(unsafe { RcBumpMember::init(self.metadata, data) })
+    }
+}
+
+impl Bump {
+    /// Try to allocate an object with shared ownership built by a closure,
+    /// reserving its room first so that it can be built in place.
+    ///
+    /// If the closure panics, the room is given back.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_rc_with<T, F: FnOnce() -> T>(&self, build: F) -> Result<RcBumpMember<T>, F> {
+        let Some((layout, offset)) = RcHeader::layout(Layout::new::<T>(), needs_drop::<T>()) else {
+            return Err(build);
+        };
+        let Some(reservation) = Reservation::claim(self, layout) else {
+            return Err(build);
+        };
+        // Safety: the value is at offset in the claimed room
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        let data =
+            unsafe { NonNull::new_unchecked(reservation.start.as_ptr().add(offset)).cast::<T>() };
+        // Safety: data is valid for writes and aligned
+        unsafe { data.as_ptr().write(build()) };
+        reservation.keep();
+        // Safety:
+        // the room for the header was claimed before the value,
+        // and the reservation handed its count over to the member
+        Ok(unsafe { RcBumpMember::init(self.metadata, data) })
+    }
+}
+
+impl Bump {
+    /// Try to allocate an object with shared ownership, built by a closure
+    /// given a weak pointer to it, like [`Rc::new_cyclic`].
+    ///
+    /// The weak pointer cannot be upgraded until the closure returns, so
+    /// values built this way are always counted individually, even if
+    /// `!T::needs_drop()`.
+    ///
+    /// Fails if there is not enough memory left, giving back the closure.
+    pub fn try_alloc_rc_cyclic<T, F>(&self, build: F) -> Result<RcBumpMember<T>, F>
+    where
+        F: FnOnce(&WeakBumpMember<T>) -> T,
+    {
+        let Some((layout, offset)) = RcHeader::layout(Layout::new::<T>(), true) else {
+            return Err(build);
+        };
+        let Some(start) = self.claim(layout) else {
+            return Err(build);
+        };
+        // Safety: the value is at offset in the claimed room, after its header
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        let (header, data) = unsafe {
+            let data = NonNull::new_unchecked(start.as_ptr().add(offset)).cast::<T>();
+            (
+                NonNull::new_unchecked(data.cast::<RcHeader>().as_ptr().sub(1)),
+                data,
+            )
+        };
+        // Safety: the header is valid for writes and aligned
+        unsafe {
+            header.as_ptr().write(RcHeader {
+                count: Cell::new(0),
+                weak: Cell::new(1),
             })
-        } else {
-            let RawBumpMember { metadata, data } = self.try_alloc_inner(value)?;
-            Ok(RcBumpMember {
-                metadata,
-                rc_data: data.cast(),
-                _marker: PhantomData,
+        };
+        // Holds the count claimed above until the value is built, so that
+        // the room is released if build panics
+        let weak = WeakBumpMember {
+            metadata: self.metadata,
+            header: Some(header),
+            data,
+        };
+        let value = build(&weak);
+        // Safety: data is valid for writes and aligned
+        unsafe { data.as_ptr().write(value) };
+        // Safety: the header is valid
+        unsafe { header.as_ref() }.count.set(1);
+        // Its weak count is the one held by the strong members
+        std::mem::forget(weak);
+        Ok(RcBumpMember {
+            metadata: self.metadata,
+            header: Some(header),
+            data,
+        })
+    }
+}
+
+impl Bump {
+    // Allocates values of the iterator, back to back, until it is
+    // exhausted or the bump is full.
+    pub(crate) fn alloc_batch<T>(
+        &self,
+        values: &mut impl ExactSizeIterator<Item = T>,
+        members: &mut Vec<BumpMember<T>>,
+    ) {
+        self.alloc_batch_with(Layout::new::<T>(), 0, values, |data| {
+            members.push(BumpMember {
+                metadata: self.metadata,
+                data,
             })
+        });
+    }
+
+    // Allocates values of the iterator with shared ownership, back to back,
+    // until it is exhausted or the bump is full.
+    pub(crate) fn alloc_rc_batch<T>(
+        &self,
+        values: &mut impl ExactSizeIterator<Item = T>,
+        members: &mut Vec<RcBumpMember<T>>,
+    ) {
+        let Some((layout, offset)) = RcHeader::layout(Layout::new::<T>(), needs_drop::<T>()) else {
+            return;
+        };
+        self.alloc_batch_with(layout, offset, values, |data| {
+            // Safety:
+            // the room for the header is right before the value, and
+            // alloc_batch_with took a count of the bump for the member
+            members.push(unsafe { RcBumpMember::init(self.metadata, data) })
+        });
+    }
+
+    // Writes values of the iterator in entries of the layout, the value
+    // being at offset in its entry, and hands each of them to push.
+    // Room for the batch is claimed before taking values out of the
+    // iterator, and the count of the bump is incremented before each value
+    // is handed over, so that members already pushed stay accounted for
+    // if the iterator panics.
+    fn alloc_batch_with<T>(
+        &self,
+        layout: Layout,
+        offset: usize,
+        values: &mut impl ExactSizeIterator<Item = T>,
+        mut push: impl FnMut(NonNull<T>),
+    ) {
+        let layout = layout.pad_to_align();
+        // Safety: the cursor is valid as long as self lives
+        let first_free = unsafe { self.cursor.as_ref() }.first_free.get();
+        let available = self
+            .remaining()
+            .saturating_sub(first_free.as_ptr().align_offset(layout.align()));
+        let batch = match layout.size() {
+            0 => values.len(),
+            size => values.len().min(available / size),
+        };
+        let Ok(array) = Layout::from_size_align(layout.size() * batch, layout.align()) else {
+            return;
+        };
+        let Some((start, end)) = self.can_fit(array) else {
+            return;
+        };
+        // Safety: the cursor is valid as long as self lives
+        unsafe { self.cursor.as_ref() }.first_free.set(end);
+        for (written, value) in values.take(batch).enumerate() {
+            // Safety:
+            // start + written * size is within the claimed array,
+            // which has room for batch entries
+            #[allow(clippy::multiple_unsafe_ops_per_block)]
+            let data = unsafe {
+                NonNull::new_unchecked(start.add(written * layout.size() + offset)).cast::<T>()
+            };
+            // Safety: data is valid for writes and aligned
+            unsafe { data.as_ptr().write(value) };
+            // Safety: metadata is valid as long as self lives
+            unsafe { Metadata::increment(self.metadata) };
+            push(data);
+        }
+    }
+}
+
+impl<T> RcBumpMember<T> {
+    // Moves the value out of the bump.
+    //
+    // # Safety
+    // self must be the only member pointing to its value
+    pub(crate) unsafe fn into_inner_unique(self) -> T {
+        let this = ManuallyDrop::new(self);
+        // Safety:
+        // the value is valid and only owned by this member, which
+        // will not be dropped, so the value is read only once
+        let value = this.data.as_ptr().read();
+        // Safety: the value was moved out
+        #[cfg(feature = "zeroize")]
+        crate::sensitive::wipe_value(this.metadata, this.data);
+        // Safety:
+        // No other reference to metadata currently exists
+        // (only pointers)
+        Metadata::decrement_and_drop(this.metadata);
+        value
+    }
+}
+
+impl RcBumpMember<[u8]> {
+    // # Safety
+    // the bytes must be valid UTF-8
+    pub(crate) unsafe fn into_str_unchecked(self) -> RcBumpMember<str> {
+        let this = ManuallyDrop::new(self);
+        RcBumpMember {
+            metadata: this.metadata,
+            header: this.header,
+            // Safety: str has the same layout as [u8], and neither needs a header
+            data: NonNull::new_unchecked(this.data.as_ptr() as *mut str),
+        }
+    }
+}
+
+impl<T: Any> RcBumpMember<T> {
+    /// Converts the member into a type-erased one.
+    ///
+    /// See [`RcBumpMember::downcast`].
+    pub fn into_any(self) -> RcBumpMember<dyn Any> {
+        let this = ManuallyDrop::new(self);
+        RcBumpMember {
+            metadata: this.metadata,
+            header: this.header,
+            data: this.data,
+        }
+    }
+}
+
+impl<T> RcBumpMember<T> {
+    // Forgets the type of the value, only remembering how to drop it
+    pub(crate) fn erase<'a>(self) -> RcBumpMember<dyn Erased + 'a>
+    where
+        T: 'a,
+    {
+        let this = ManuallyDrop::new(self);
+        RcBumpMember {
+            metadata: this.metadata,
+            header: this.header,
+            data: this.data,
+        }
+    }
+}
+
+impl RcBumpMember<dyn Any> {
+    /// Try to convert a type-erased member back into a typed one,
+    /// failing if `T` is not the type of the pointee.
+    pub fn downcast<T: Any>(self) -> Result<RcBumpMember<T>, Self> {
+        if !self.is::<T>() {
+            return Err(self);
+        }
+        let this = ManuallyDrop::new(self);
+        Ok(RcBumpMember {
+            metadata: this.metadata,
+            header: this.header,
+            data: this.data.cast(),
+        })
+    }
+}
+
+impl<T> RcBumpMember<T> {
+    // Used by alloc_dyn! to coerce the pointer at the call site, where
+    // the concrete type is known.
+    //
+    // # Safety
+    // coerce must return its argument, only changing its type
+    #[doc(hidden)]
+    pub unsafe fn __unsize<U: ?Sized>(
+        self,
+        coerce: impl FnOnce(*mut T) -> *mut U,
+    ) -> RcBumpMember<U> {
+        let this = ManuallyDrop::new(self);
+        RcBumpMember {
+            metadata: this.metadata,
+            header: this.header,
+            data: NonNull::new_unchecked(coerce(this.data.as_ptr())),
         }
     }
 }
 
-impl<T> Deref for RcBumpMember<T> {
+/// Allocates a value with shared ownership in a [`Bump`] or a
+/// [`Paving`], returning a member to a trait object it implements.
+///
+/// This works on stable, where members cannot be coerced.
+/// It evaluates to `Err(value)` if the allocation fails.
+///
+/// ```
+/// use std::fmt::Display;
+///
+/// let paving = rc_bump::Paving::new(1024, 8);
+/// let shown: Vec<rc_bump::RcBumpMember<dyn Display>> = vec![
+///     rc_bump::alloc_dyn!(paving, dyn Display, 1).ok().unwrap(),
+///     rc_bump::alloc_dyn!(paving, dyn Display, "two").ok().unwrap(),
+/// ];
+/// assert_eq!(shown[1].to_string(), "two");
+/// ```
+#[macro_export]
+macro_rules! alloc_dyn {
+    ($arena:expr, $ty:ty, $value:expr) => {
+        $arena.try_alloc_rc($value).map(|member| {
+            // Safety: the pointer is only coerced
+            unsafe { member.__unsize(|ptr| ptr as *mut $ty) }
+        })
+    };
+}
+
+impl<T, const N: usize> RcBumpMember<[T; N]> {
+    /// Converts a member to an array into a member to a slice.
+    pub fn into_slice(self) -> RcBumpMember<[T]> {
+        let this = ManuallyDrop::new(self);
+        RcBumpMember {
+            metadata: this.metadata,
+            header: this.header,
+            data: this.data,
+        }
+    }
+}
+
+impl<T: ?Sized> RcBumpMember<T> {
+    /// Returns a pointer to the value, for instance to tell members apart.
+    ///
+    /// See [`BumpMember::as_ptr`].
+    pub fn as_ptr(this: &Self) -> *const T {
+        this.data.as_ptr()
+    }
+}
+
+// Only sized values have a header exactly when they need to be dropped:
+// an unsized member may have been coerced from a value without one.
+#[cfg(feature = "raw")]
+impl<T> RcBumpMember<T> {
+    /// Consumes the member, returning a pointer to its value, like
+    /// [`Rc::into_raw`]. The value stays shared until a member is rebuilt
+    /// with [`RcBumpMember::from_raw`].
+    ///
+    /// Unlike [`BumpMember::into_raw`], this is only available for sized
+    /// values, whose header can be found back from their type.
+    pub fn into_raw(this: Self) -> *const T {
+        let this = ManuallyDrop::new(this);
+        crate::raw::register(this.metadata);
+        this.data.as_ptr()
+    }
+
+    /// Rebuilds a member from a pointer returned by [`RcBumpMember::into_raw`],
+    /// finding the bump of the value from its address.
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must come from [`RcBumpMember::into_raw`] on a member to
+    ///   the same type, on the same thread, and be rebuilt only once
+    /// - if `!T::needs_drop()`, the value must not have been allocated with
+    ///   [`Bump::try_alloc_rc_cyclic`], which counts it individually
+    pub unsafe fn from_raw(ptr: *const T) -> Self {
+        let data = NonNull::new_unchecked(ptr.cast_mut());
+        RcBumpMember {
+            // Safety: the member kept the bump of the value alive
+            metadata: crate::raw::take_chunk_of(ptr.cast()).unwrap_unchecked(),
+            header: RcHeader::of(data),
+            data,
+        }
+    }
+}
+
+impl<T: ?Sized> Deref for RcBumpMember<T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
-        match self.rc_data() {
-            // Safety: self contains a valid data entry
-            NeedsDrop::Yes(rc_entry) => unsafe { &rc_entry.as_ref().value },
-            // Safety: self contains a valid data entry
-            NeedsDrop::No(value) => unsafe { value.as_ref() },
-        }
+        // Safety: self points to a valid value
+        unsafe { self.data.as_ref() }
+    }
+}
+
+impl<T: ?Sized> RcBumpMember<T> {
+    /// Returns a handle to the bump this member lives in,
+    /// to allocate objects next to it.
+    pub fn arena(&self) -> Bump {
+        Bump::of_member(self)
+    }
+}
+
+impl<T: Clone> RcBumpMember<T> {
+    /// Clones the value out of the bump into an [`Rc`].
+    pub fn to_rc(&self) -> Rc<T> {
+        Rc::new(T::clone(self))
+    }
+
+    /// Clones the value out of the bump into an [`Arc`].
+    pub fn to_arc(&self) -> Arc<T> {
+        Arc::new(T::clone(self))
+    }
+}
+
+impl<T: ?Sized> Sealed for RcBumpMember<T> {
+    fn metadata(&self) -> NonNull<Metadata> {
+        self.metadata
+    }
+
+    #[cfg(feature = "provenance")]
+    fn data(&self) -> NonNull<u8> {
+        self.data.cast()
     }
 }
 
-impl<T> Drop for RcBumpMember<T> {
+impl<T: ?Sized> Drop for RcBumpMember<T> {
     fn drop(&mut self) {
-        match self.rc_data() {
-            NeedsDrop::Yes(mut rc_entry) => {
-                // Safety: rc_entry points to a valid BumpRcEntry
-                unsafe { rc_entry.as_mut().count -= 1 };
-                // Safety: rc_entry points to a valid BumpRcEntry
-                if unsafe { rc_entry.as_ref().count == 0 } {
-                    #[allow(clippy::multiple_unsafe_ops_per_block)]
-                    // Safety: rc entry points to valid data
+        match self.header {
+            Some(header) => {
+                // Safety: the header of the value is valid
+                let count = &unsafe { header.as_ref() }.count;
+                count.set(count.get() - 1);
+                if count.get() == 0 {
+                    // Safety: the value is valid until dropped below
+                    #[cfg(feature = "zeroize")]
+                    let size = std::mem::size_of_val(unsafe { self.data.as_ref() });
+                    // Safety: self points to a valid value
+                    unsafe { drop_in_place(self.data.as_ptr()) };
+                    // Safety: the value was dropped
+                    #[cfg(feature = "zeroize")]
                     unsafe {
-                        drop_in_place(addr_of_mut!((*rc_entry.as_ptr()).value))
+                        crate::sensitive::wipe_bytes(self.metadata, self.data.cast(), size)
                     };
                     // Safety:
-                    // No other reference to metadata currently exists
-                    // (only pointers)
-                    unsafe { Metadata::decrement_and_drop(self.metadata) };
+                    // The strong members held one weak count, and
+                    // no other reference to metadata currently exists
+                    unsafe { release_weak(self.metadata, header) };
                 }
             }
             // Safety:
             // No other reference to metadata currently exists
             // (only pointers)
-            NeedsDrop::No(_) => unsafe { Metadata::decrement_and_drop(self.metadata) },
+            None => unsafe { Metadata::decrement_and_drop(self.metadata) },
+        }
+    }
+}
+
+// Releases a weak count of the header, and the count it holds
+// on its bump once there is none left.
+//
+// # Safety
+// - header must point to a valid RcHeader holding a weak count
+// - No live reference to the metadata must exist
+unsafe fn release_weak(metadata: NonNull<Metadata>, header: NonNull<RcHeader>) {
+    let weak = &header.as_ref().weak;
+    weak.set(weak.get() - 1);
+    if weak.get() == 0 {
+        Metadata::decrement_and_drop(metadata);
+    }
+}
+
+impl<T: ?Sized> Clone for RcBumpMember<T> {
+    fn clone(&self) -> Self {
+        match self.header {
+            Some(header) => {
+                // Safety: the header of the value is valid
+                let count = &unsafe { header.as_ref() }.count;
+                count.set(count.get() + 1);
+            }
+            // Safety: metadata is valid
+            None => unsafe { Metadata::increment(self.metadata) },
+        }
+        Self {
+            metadata: self.metadata,
+            header: self.header,
+            data: self.data,
+        }
+    }
+}
+
+impl<T: ?Sized> RcBumpMember<T> {
+    /// The number of members sharing the value, like [`Rc::strong_count`].
+    ///
+    /// If `!T::needs_drop()`, values are not counted individually and this
+    /// is the number of pointers keeping their bump alive, which is at least
+    /// the number of members sharing the value.
+    pub fn strong_count(&self) -> usize {
+        match self.header {
+            // Safety: the header of the value is valid
+            Some(header) => unsafe { header.as_ref() }.count.get(),
+            None => {
+                // Safety: metadata is valid as long as self lives
+                let count = unsafe { self.metadata.as_ref() }
+                    .count
+                    .load(Ordering::Relaxed);
+                usize::try_from(count).unwrap_or(usize::MAX)
+            }
+        }
+    }
+
+    /// Returns a mutable reference to the value if no other member nor
+    /// weak pointer shares it, like [`Rc::get_mut`].
+    ///
+    /// If `!T::needs_drop()`, values are not counted individually, and this
+    /// only succeeds if nothing else keeps their bump alive.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        let unique = match self.header {
+            Some(header) => {
+                // Safety: the header of the value is valid
+                let header = unsafe { header.as_ref() };
+                header.count.get() == 1 && header.weak.get() == 1
+            }
+            None => {
+                // Safety: metadata is valid as long as self lives
+                let count = &unsafe { self.metadata.as_ref() }.count;
+                // Acquire, so that uses of the bump on other threads through
+                // ArcBumpMember happen before the mutation
+                count.load(Ordering::Acquire) == 1
+            }
+        };
+        if !unique {
+            return None;
+        }
+        // Safety: no other pointer can reach the value
+        Some(unsafe { self.data.as_mut() })
+    }
+
+    /// Creates a weak pointer to the value, which does not keep it alive.
+    pub fn downgrade(&self) -> WeakBumpMember<T> {
+        match self.header {
+            Some(header) => {
+                // Safety: the header of the value is valid
+                let weak = &unsafe { header.as_ref() }.weak;
+                weak.set(weak.get() + 1);
+            }
+            // Safety: metadata is valid
+            None => unsafe { Metadata::increment(self.metadata) },
+        }
+        WeakBumpMember {
+            metadata: self.metadata,
+            header: self.header,
+            data: self.data,
+        }
+    }
+}
+
+/// A weak pointer to a value owned by [`RcBumpMember`]s, similar
+/// to [`std::rc::Weak`].
+///
+/// It does not keep the value alive, which allows building cyclic
+/// graphs without leaking them. It keeps the memory of the value
+/// allocated though, and so the bump it lives in.
+///
+/// If `!T::needs_drop()`, the value is never dropped, and the weak
+/// pointer can always be upgraded.
+pub struct WeakBumpMember<T: ?Sized> {
+    metadata: NonNull<Metadata>,
+    header: Option<NonNull<RcHeader>>,
+    data: NonNull<T>,
+}
+
+impl<T: ?Sized> WeakBumpMember<T> {
+    /// Returns a strong pointer to the value, or None if it was dropped.
+    pub fn upgrade(&self) -> Option<RcBumpMember<T>> {
+        match self.header {
+            Some(header) => {
+                // Safety: the header of the value is valid
+                let count = &unsafe { header.as_ref() }.count;
+                if count.get() == 0 {
+                    return None;
+                }
+                count.set(count.get() + 1);
+            }
+            // Safety: metadata is valid
+            None => unsafe { Metadata::increment(self.metadata) },
         }
+        Some(RcBumpMember {
+            metadata: self.metadata,
+            header: self.header,
+            data: self.data,
+        })
     }
 }
 
-impl<T> Clone for RcBumpMember<T> {
+impl<T: ?Sized> Clone for WeakBumpMember<T> {
     fn clone(&self) -> Self {
-        match self.rc_data() {
-            // Safety: self contains a valid rc_data entry
-            NeedsDrop::Yes(mut rc_data) => unsafe { rc_data.as_mut().count += 1 },
+        match self.header {
+            Some(header) => {
+                // Safety: the header of the value is valid
+                let weak = &unsafe { header.as_ref() }.weak;
+                weak.set(weak.get() + 1);
+            }
             // Safety: metadata is valid
-            NeedsDrop::No(_) => unsafe { (*self.metadata.as_ptr()).count += 1 },
+            None => unsafe { Metadata::increment(self.metadata) },
         }
         Self {
             metadata: self.metadata,
-            rc_data: self.rc_data,
-            _marker: PhantomData,
+            header: self.header,
+            data: self.data,
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for WeakBumpMember<T> {
+    fn drop(&mut self) {
+        match self.header {
+            // Safety:
+            // self holds a weak count, and no other reference
+            // to metadata currently exists
+            Some(header) => unsafe { release_weak(self.metadata, header) },
+            // Safety:
+            // No other reference to metadata currently exists
+            // (only pointers)
+            None => unsafe { Metadata::decrement_and_drop(self.metadata) },
         }
     }
 }
@@ -0,0 +1,112 @@
+//! Single-threaded channels backed by arenas.
+
+use std::{cell::RefCell, collections::VecDeque, mem::align_of, rc::Rc};
+
+use crate::{BumpMember, NewBumpError, Paving};
+
+// The state shared by both ends of a channel.
+// Slots of received messages are kept for later messages, so that
+// a channel in a steady state does not allocate.
+struct Shared<T> {
+    paving: Paving,
+    queue: VecDeque<BumpMember<Option<T>>>,
+    free: Vec<BumpMember<Option<T>>>,
+}
+
+/// The sending end of a channel, see [`try_channel`].
+pub struct Sender<T> {
+    shared: Rc<RefCell<Shared<T>>>,
+}
+
+/// The receiving end of a channel, see [`try_channel`].
+pub struct Receiver<T> {
+    shared: Rc<RefCell<Shared<T>>>,
+}
+
+/// Try to create a channel whose messages are stored in a paving
+/// with bumps of the given capacity.
+///
+/// See [`Paving::try_new`].
+pub fn try_channel<T>(capacity: usize) -> Result<(Sender<T>, Receiver<T>), NewBumpError> {
+    let shared = Rc::new(RefCell::new(Shared {
+        paving: Paving::try_new(capacity, align_of::<Option<T>>())?,
+        queue: VecDeque::new(),
+        free: Vec::new(),
+    }));
+    Ok((
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    ))
+}
+
+/// Creates a channel whose messages are stored in a paving
+/// with bumps of the given capacity.
+///
+/// See [`try_channel`].
+#[cfg_attr(
+    feature = "panic_free",
+    deprecated = "may panic, which the `panic_free` feature forbids"
+)]
+pub fn channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    try_channel(capacity).unwrap_or_else(|err| err.panic())
+}
+
+impl<T> Sender<T> {
+    /// Try to send a message.
+    ///
+    /// Fails if the receiver was dropped, or if no room can be
+    /// allocated for the message.
+    pub fn try_send(&self, value: T) -> Result<(), T> {
+        if Rc::strong_count(&self.shared) == 1 {
+            return Err(value);
+        }
+        let Ok(mut shared) = self.shared.try_borrow_mut() else {
+            return Err(value);
+        };
+        let slot = match shared.free.pop() {
+            Some(mut slot) => {
+                *slot = Some(value);
+                slot
+            }
+            None => match shared.paving.try_alloc(None) {
+                Ok(mut slot) => {
+                    *slot = Some(value);
+                    slot
+                }
+                Err(_) => return Err(value),
+            },
+        };
+        shared.queue.push_back(slot);
+        Ok(())
+    }
+}
+
+impl<T> Receiver<T> {
+    /// Receives the oldest message, or None if there is none.
+    pub fn try_recv(&self) -> Option<T> {
+        let mut shared = self.shared.try_borrow_mut().ok()?;
+        let mut slot = shared.queue.pop_front()?;
+        let value = Option::take(&mut slot);
+        shared.free.push(slot);
+        value
+    }
+
+    /// The number of messages waiting to be received
+    pub fn len(&self) -> usize {
+        self.shared
+            .try_borrow()
+            .map_or(0, |shared| shared.queue.len())
+    }
+
+    /// Returns true if no message is waiting to be received
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns true if the sender was dropped
+    pub fn is_disconnected(&self) -> bool {
+        Rc::strong_count(&self.shared) == 1
+    }
+}
@@ -0,0 +1,182 @@
+use std::{cell::Cell, iter, ops::Range, ptr::NonNull, rc::Rc};
+
+#[cfg(feature = "registry")]
+use std::sync::Arc;
+
+#[cfg(feature = "registry")]
+use crate::registry::Registration;
+
+use crate::{metadata::Metadata, Bump, Paving};
+
+/// A description of a chunk of memory backing a bump,
+/// as returned by [`Paving::chunks`].
+#[derive(Debug, Clone)]
+pub struct ChunkInfo {
+    /// The address of the first byte of the chunk
+    pub base: *const u8,
+    /// The number of bytes available for allocations in the chunk
+    pub capacity: usize,
+    /// The range of bytes, as offsets from `base`, already allocated
+    pub used: Range<usize>,
+}
+
+/// A summary of the memory used by a paving or a bump,
+/// as returned by [`Paving::snapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StatsSnapshot {
+    /// The number of live chunks
+    pub chunks: usize,
+    /// The number of bytes available for allocations in the chunks
+    pub capacity: usize,
+    /// The number of bytes already allocated in the chunks
+    pub used: usize,
+}
+
+impl StatsSnapshot {
+    fn add(mut self, info: &ChunkInfo) -> Self {
+        self.chunks += 1;
+        self.capacity += info.capacity;
+        self.used += info.used.len();
+        self
+    }
+}
+
+// The live bumps of a paving, as an intrusive doubly linked list
+// threaded through their metadata.
+//
+// Every linked bump holds a strong reference to the list, so that it
+// can unlink itself when deallocated, even after the paving is gone.
+//
+// The list keeps the number and total capacity of its bumps up to date,
+// so that checking them does not require walking it.
+#[derive(Default)]
+pub(crate) struct ChunkList {
+    head: Cell<Option<NonNull<Metadata>>>,
+    chunks: Cell<usize>,
+    capacity: Cell<usize>,
+    #[cfg(feature = "registry")]
+    registration: Cell<Option<Arc<Registration>>>,
+}
+
+#[derive(Default)]
+pub(crate) struct ChunkLink {
+    list: Cell<Option<Rc<ChunkList>>>,
+    prev: Cell<Option<NonNull<Metadata>>>,
+    next: Cell<Option<NonNull<Metadata>>>,
+}
+
+impl ChunkList {
+    // # Safety
+    // - metadata must not be dangling
+    // - metadata must not be linked already
+    pub(crate) unsafe fn link(self: &Rc<Self>, metadata: NonNull<Metadata>) {
+        let link = &metadata.as_ref().link;
+        let head = self.head.replace(Some(metadata));
+        if let Some(head) = head {
+            head.as_ref().link.prev.set(Some(metadata));
+        }
+        link.next.set(head);
+        link.list.set(Some(self.clone()));
+        self.chunks.set(self.chunks.get() + 1);
+        self.capacity
+            .set(self.capacity.get() + metadata.as_ref().capacity());
+        #[cfg(feature = "registry")]
+        self.with_registration(|registration| registration.add(metadata.as_ref().layout.size()));
+    }
+
+    #[cfg(feature = "registry")]
+    fn with_registration(&self, f: impl FnOnce(&Registration)) {
+        let registration = self.registration.take();
+        if let Some(registration) = &registration {
+            f(registration);
+        }
+        self.registration.set(registration);
+    }
+
+    #[cfg(feature = "registry")]
+    pub(crate) fn set_registration(&self, registration: Option<Arc<Registration>>) {
+        self.registration.set(registration);
+    }
+
+    // Returns the number of live bumps of the list and their total capacity
+    pub(crate) fn totals(&self) -> (usize, usize) {
+        (self.chunks.get(), self.capacity.get())
+    }
+
+    // Iterates over the metadata of the live bumps of the list,
+    // from the most recently linked one.
+    //
+    // Bumps must not be deallocated during the iteration.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = NonNull<Metadata>> + '_ {
+        iter::successors(self.head.get(), |metadata| {
+            // Safety: linked metadata are alive
+            unsafe { metadata.as_ref() }.link.next.get()
+        })
+    }
+}
+
+impl ChunkLink {
+    pub(crate) fn is_linked(&self) -> bool {
+        let list = self.list.take();
+        let linked = list.is_some();
+        self.list.set(list);
+        linked
+    }
+
+    // # Safety
+    // - metadata must not be dangling
+    pub(crate) unsafe fn unlink(metadata: NonNull<Metadata>) {
+        let link = &metadata.as_ref().link;
+        let Some(list) = link.list.take() else {
+            return;
+        };
+        let prev = link.prev.take();
+        let next = link.next.take();
+        match prev {
+            Some(prev) => prev.as_ref().link.next.set(next),
+            None => list.head.set(next),
+        }
+        if let Some(next) = next {
+            next.as_ref().link.prev.set(prev);
+        }
+        list.chunks.set(list.chunks.get() - 1);
+        list.capacity
+            .set(list.capacity.get() - metadata.as_ref().capacity());
+        #[cfg(feature = "registry")]
+        list.with_registration(|registration| registration.remove(metadata.as_ref().layout.size()));
+    }
+}
+
+impl Paving {
+    /// Returns a description of every live chunk created by this paving,
+    /// from the most recently created one.
+    ///
+    /// This is meant for diagnostics: the returned addresses can be used
+    /// to inspect the chunks, as long as they are not deallocated.
+    pub fn chunks(&self) -> impl Iterator<Item = ChunkInfo> {
+        let infos: Vec<ChunkInfo> = self
+            .chunk_list()
+            .iter()
+            // Safety: linked metadata are alive
+            .map(|metadata| unsafe { metadata.as_ref() }.info())
+            .collect();
+        infos.into_iter()
+    }
+}
+
+impl Paving {
+    /// Returns a summary of the memory used by the live chunks of the paving.
+    pub fn snapshot(&self) -> StatsSnapshot {
+        self.chunks()
+            .fold(StatsSnapshot::default(), |stats, info| stats.add(&info))
+    }
+}
+
+impl Bump {
+    /// Returns a summary of the memory used by the chunk of the bump.
+    pub fn snapshot(&self) -> StatsSnapshot {
+        // Safety: metadata is valid as long as self lives
+        StatsSnapshot::default().add(&unsafe { self.metadata().as_ref() }.info())
+    }
+}
@@ -0,0 +1,12 @@
+use std::{marker::Unsize, ops::CoerceUnsized};
+
+use crate::{BumpMember, RcBumpMember, WeakBumpMember};
+
+// Members can be coerced like Box and Rc, for instance from
+// RcBumpMember<T> into RcBumpMember<dyn Trait> where T: Trait.
+
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<BumpMember<U>> for BumpMember<T> {}
+
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<RcBumpMember<U>> for RcBumpMember<T> {}
+
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<WeakBumpMember<U>> for WeakBumpMember<T> {}
@@ -0,0 +1,510 @@
+//! Growable collections whose storage lives in arenas.
+
+use std::{
+    alloc::Layout,
+    fmt,
+    mem::{needs_drop, size_of, ManuallyDrop},
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+};
+
+use crate::{bump::RcHeader, metadata::Metadata, Bump, Paving, RcBumpMember};
+
+// Where a collection allocates its storage
+#[derive(Clone, Copy)]
+enum Arena<'a> {
+    Bump(&'a Bump),
+    Paving(&'a Paving),
+}
+
+impl Arena<'_> {
+    // Reserves room for the layout, holding a count on the bump it lands in
+    fn claim(self, layout: Layout) -> Option<(NonNull<Metadata>, NonNull<u8>)> {
+        let claim = |bump: &Bump| Some((bump.metadata(), bump.claim(layout)?));
+        match self {
+            Arena::Bump(bump) => claim(bump),
+            Arena::Paving(paving) => paving
+                .try_alloc_inner(layout, (), |bump, ()| claim(bump).ok_or(()))
+                .ok(),
+        }
+    }
+}
+
+/// A vector whose storage lives in a [`Bump`] or a [`Paving`], similar to
+/// bumpalo's `collections::Vec`.
+///
+/// It grows in place while its storage is the last allocation of its bump,
+/// and is moved to a larger storage otherwise. Once filled, it can be frozen
+/// into an [`RcBumpMember<[T]>`](RcBumpMember) without copying.
+pub struct BumpVec<'a, T> {
+    arena: Arena<'a>,
+    // The bump of the storage, on which a count is held, if any
+    metadata: Option<NonNull<Metadata>>,
+    data: NonNull<T>,
+    len: usize,
+    capacity: usize,
+}
+
+impl<'a, T> BumpVec<'a, T> {
+    /// Creates an empty vector allocating its storage in the paving.
+    ///
+    /// It does not allocate until elements are pushed.
+    pub fn new_in(paving: &'a Paving) -> Self {
+        Self::new(Arena::Paving(paving))
+    }
+
+    /// Creates an empty vector allocating its storage in the bump.
+    ///
+    /// It does not allocate until elements are pushed.
+    pub fn new_in_bump(bump: &'a Bump) -> Self {
+        Self::new(Arena::Bump(bump))
+    }
+
+    fn new(arena: Arena<'a>) -> Self {
+        Self {
+            arena,
+            metadata: None,
+            data: NonNull::dangling(),
+            len: 0,
+            capacity: 0,
+        }
+    }
+
+    // The layout of a storage of the given capacity, with room for the
+    // header of a shared slice, and the offset of the elements in it
+    fn storage_layout(capacity: usize) -> Option<(Layout, usize)> {
+        RcHeader::layout(Layout::array::<T>(capacity).ok()?, needs_drop::<T>())
+    }
+
+    /// The number of elements of the vector
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the vector holds no element
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of elements the vector can hold without growing
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Try to make room for at least `additional` more elements.
+    ///
+    /// Fails if the storage can neither grow in place nor be moved
+    /// to a large enough one.
+    pub fn try_reserve(&mut self, additional: usize) -> bool {
+        let Some(required) = self.len.checked_add(additional) else {
+            return false;
+        };
+        if required <= self.capacity {
+            return true;
+        }
+        let capacity = required.max(self.capacity * 2).max(4);
+        self.try_grow_in_place(capacity) || self.try_reallocate(capacity)
+    }
+
+    fn try_grow_in_place(&mut self, capacity: usize) -> bool {
+        let Some(metadata) = self.metadata else {
+            return false;
+        };
+        if Self::storage_layout(capacity).is_none() {
+            return false;
+        }
+        // Safety: the storage keeps its bump alive
+        let metadata = unsafe { metadata.as_ref() };
+        // Safety: the storage holds capacity elements
+        let end = unsafe { self.data.as_ptr().add(self.capacity) };
+        let additional = (capacity - self.capacity) * size_of::<T>();
+        // Safety: end is one past the storage
+        if !metadata.try_extend(unsafe { NonNull::new_unchecked(end.cast()) }, additional) {
+            return false;
+        }
+        self.capacity = capacity;
+        true
+    }
+
+    fn try_reallocate(&mut self, capacity: usize) -> bool {
+        let Some((layout, offset)) = Self::storage_layout(capacity) else {
+            return false;
+        };
+        let Some((metadata, start)) = self.arena.claim(layout) else {
+            return false;
+        };
+        // Safety: the elements are at offset in the claimed room
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        let data = unsafe { NonNull::new_unchecked(start.as_ptr().add(offset)).cast::<T>() };
+        // Safety:
+        // the new storage has room for the elements, and was just
+        // claimed so it cannot overlap with the old one
+        unsafe { ptr::copy_nonoverlapping(self.data.as_ptr(), data.as_ptr(), self.len) };
+        self.release_storage();
+        self.metadata = Some(metadata);
+        self.data = data;
+        self.capacity = if size_of::<T>() == 0 {
+            usize::MAX
+        } else {
+            capacity
+        };
+        true
+    }
+
+    // Gives back the storage, whose elements were moved out or dropped
+    fn release_storage(&mut self) {
+        let Some(metadata) = self.metadata.take() else {
+            return;
+        };
+        // Infallible: the layout was valid when the storage was allocated
+        let Some((_, offset)) = Self::storage_layout(self.capacity) else {
+            return;
+        };
+        // Safety: the storage keeps its bump alive
+        let metadata_ref = unsafe { metadata.as_ref() };
+        // Safety: the storage is in the bump, preceded by room for a header
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        unsafe {
+            let start = NonNull::new_unchecked(self.data.as_ptr().cast::<u8>().sub(offset));
+            let end = NonNull::new_unchecked(self.data.as_ptr().add(self.capacity).cast());
+            #[cfg(feature = "zeroize")]
+            crate::sensitive::wipe_bytes(
+                metadata,
+                self.data.cast(),
+                self.capacity * size_of::<T>(),
+            );
+            metadata_ref.try_rewind(start, end);
+        }
+        // Safety:
+        // No other reference to metadata currently exists
+        // (only pointers)
+        unsafe { Metadata::decrement_and_drop(metadata) };
+    }
+
+    /// Try to append an element at the end of the vector.
+    ///
+    /// Fails if no room can be made for it, see [`Self::try_reserve`].
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        if !self.try_reserve(1) {
+            return Err(value);
+        }
+        // Safety: the storage has room for one more element
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        unsafe {
+            self.data.as_ptr().add(self.len).write(value)
+        };
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Appends an element at the end of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no room can be made for it.
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids",
+        allow(clippy::panic)
+    )]
+    pub fn push(&mut self, value: T) {
+        if self.try_push(value).is_err() {
+            panic!("Could not grow a BumpVec");
+        }
+    }
+
+    /// Try to append copies of the elements of a slice.
+    ///
+    /// Fails if no room can be made for them, see [`Self::try_reserve`].
+    pub fn try_extend_from_slice<'s>(&mut self, values: &'s [T]) -> Result<(), &'s [T]>
+    where
+        T: Copy,
+    {
+        if !self.try_reserve(values.len()) {
+            return Err(values);
+        }
+        // Safety:
+        // the storage has room for the values, past the elements,
+        // and cannot overlap with a borrowed slice
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        unsafe {
+            ptr::copy_nonoverlapping(
+                values.as_ptr(),
+                self.data.as_ptr().add(self.len),
+                values.len(),
+            )
+        };
+        self.len += values.len();
+        Ok(())
+    }
+
+    /// Removes the last element of the vector and returns it, if any.
+    pub fn pop(&mut self) -> Option<T> {
+        self.len = self.len.checked_sub(1)?;
+        // Safety: the element was initialized and is not part of the vector anymore
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        Some(unsafe { self.data.as_ptr().add(self.len).read() })
+    }
+
+    /// Drops the elements past the first `len` ones.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        let tail = ptr::slice_from_raw_parts_mut(
+            // Safety: len is within the elements
+            unsafe { self.data.as_ptr().add(len) },
+            self.len - len,
+        );
+        // The length is updated first, in case dropping an element panics
+        self.len = len;
+        // Safety: the tail elements are initialized and not part of the vector anymore
+        unsafe { ptr::drop_in_place(tail) };
+    }
+
+    /// Drops all the elements, keeping the storage.
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    /// Try to freeze the vector into a slice with shared ownership,
+    /// without copying.
+    ///
+    /// The unused capacity is given back to the bump if the storage is its
+    /// last allocation. Fails only if the vector never allocated and no room
+    /// can be found for an empty slice.
+    pub fn try_freeze(mut self) -> Result<RcBumpMember<[T]>, Self> {
+        if self.metadata.is_none() && !self.try_reallocate(0) {
+            return Err(self);
+        }
+        let Some(metadata) = self.metadata else {
+            return Err(self);
+        };
+        let this = ManuallyDrop::new(self);
+        // Safety: the storage keeps its bump alive
+        let metadata_ref = unsafe { metadata.as_ref() };
+        // Safety: both pointers are within or one past the storage
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        unsafe {
+            let len_end = NonNull::new_unchecked(this.data.as_ptr().add(this.len).cast());
+            let end = NonNull::new_unchecked(this.data.as_ptr().add(this.capacity).cast());
+            metadata_ref.try_rewind(len_end, end);
+        }
+        let data = NonNull::slice_from_raw_parts(this.data, this.len);
+        // Safety:
+        // - the storage is preceded by room for a header
+        // - the count held by the vector is handed over to the member
+        Ok(unsafe { RcBumpMember::init(metadata, data) })
+    }
+}
+
+impl<T> Deref for BumpVec<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        // Safety: the first len elements are initialized
+        unsafe { NonNull::slice_from_raw_parts(self.data, self.len).as_ref() }
+    }
+}
+
+impl<T> DerefMut for BumpVec<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Safety: the first len elements are initialized and only reachable through self
+        unsafe { NonNull::slice_from_raw_parts(self.data, self.len).as_mut() }
+    }
+}
+
+impl<T> Drop for BumpVec<'_, T> {
+    fn drop(&mut self) {
+        self.clear();
+        self.release_storage();
+    }
+}
+
+/// A string whose storage lives in a [`Bump`] or a [`Paving`], similar to
+/// bumpalo's `collections::String`.
+///
+/// See [`BumpVec`], which it is built upon.
+pub struct BumpString<'a> {
+    bytes: BumpVec<'a, u8>,
+}
+
+impl<'a> BumpString<'a> {
+    /// Creates an empty string allocating its storage in the paving.
+    ///
+    /// It does not allocate until text is pushed.
+    pub fn new_in(paving: &'a Paving) -> Self {
+        Self {
+            bytes: BumpVec::new_in(paving),
+        }
+    }
+
+    /// Creates an empty string allocating its storage in the bump.
+    ///
+    /// It does not allocate until text is pushed.
+    pub fn new_in_bump(bump: &'a Bump) -> Self {
+        Self {
+            bytes: BumpVec::new_in_bump(bump),
+        }
+    }
+
+    /// Try to create a string holding a copy of `s`, allocated in the paving.
+    ///
+    /// As the arena must be provided, this stands for `From<&str>`.
+    pub fn try_from_str_in(s: &str, paving: &'a Paving) -> Option<Self> {
+        let mut string = Self::new_in(paving);
+        string.try_push_str(s).ok()?;
+        Some(string)
+    }
+
+    /// Creates a string holding a copy of `s`, allocated in the paving.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no room can be allocated for it.
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids",
+        allow(deprecated)
+    )]
+    pub fn from_str_in(s: &str, paving: &'a Paving) -> Self {
+        let mut string = Self::new_in(paving);
+        string.push_str(s);
+        string
+    }
+
+    /// The length of the string, in bytes
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Whether the string is empty
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// The number of bytes the string can hold without growing
+    pub fn capacity(&self) -> usize {
+        self.bytes.capacity()
+    }
+
+    /// Try to append a string at the end of this one.
+    ///
+    /// Fails if no room can be made for it, see [`BumpVec::try_reserve`].
+    pub fn try_push_str<'s>(&mut self, s: &'s str) -> Result<(), &'s str> {
+        self.bytes
+            .try_extend_from_slice(s.as_bytes())
+            .map_err(|_| s)
+    }
+
+    /// Appends a string at the end of this one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no room can be made for it.
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids",
+        allow(clippy::panic)
+    )]
+    pub fn push_str(&mut self, s: &str) {
+        if self.try_push_str(s).is_err() {
+            panic!("Could not grow a BumpString");
+        }
+    }
+
+    /// Try to append a char at the end of the string.
+    ///
+    /// Fails if no room can be made for it.
+    pub fn try_push(&mut self, c: char) -> Result<(), char> {
+        self.try_push_str(c.encode_utf8(&mut [0; 4])).map_err(|_| c)
+    }
+
+    /// Removes all the text, keeping the storage.
+    pub fn clear(&mut self) {
+        self.bytes.clear()
+    }
+
+    /// Try to freeze the string into a `str` with shared ownership,
+    /// without copying.
+    ///
+    /// See [`BumpVec::try_freeze`].
+    pub fn try_into_member(self) -> Result<RcBumpMember<str>, Self> {
+        match self.bytes.try_freeze() {
+            // Safety: the bytes were only ever appended from strs
+            Ok(bytes) => Ok(unsafe { bytes.into_str_unchecked() }),
+            Err(bytes) => Err(Self { bytes }),
+        }
+    }
+
+    /// Freezes the string into a `str` with shared ownership,
+    /// without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string is empty and no room can be found for it.
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids",
+        allow(clippy::panic)
+    )]
+    pub fn into_member(self) -> RcBumpMember<str> {
+        match self.try_into_member() {
+            Ok(member) => member,
+            Err(_) => panic!("Could not freeze a BumpString"),
+        }
+    }
+}
+
+impl Deref for BumpString<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        // Safety: the bytes were only ever appended from strs
+        unsafe { std::str::from_utf8_unchecked(&self.bytes) }
+    }
+}
+
+impl fmt::Write for BumpString<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl fmt::Display for BumpString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl fmt::Debug for BumpString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// Try to format the arguments directly into the paving.
+///
+/// This is the function behind [`bump_format!`](crate::bump_format).
+pub fn try_format(paving: &Paving, args: fmt::Arguments<'_>) -> Option<RcBumpMember<str>> {
+    let mut string = BumpString::new_in(paving);
+    fmt::Write::write_fmt(&mut string, args).ok()?;
+    string.try_into_member().ok()
+}
+
+/// Formats the arguments directly into a paving, returning an
+/// [`RcBumpMember<str>`](RcBumpMember) without an intermediate `String`.
+///
+/// Evaluates to None if the paving cannot hold the text.
+///
+/// ```
+/// let paving = rc_bump::Paving::new(1024, 8);
+/// let text = rc_bump::bump_format!(paving, "{} + {} = {}", 1, 2, 1 + 2).unwrap();
+/// assert_eq!(&*text, "1 + 2 = 3");
+/// ```
+#[macro_export]
+macro_rules! bump_format {
+    ($paving:expr, $($arg:tt)*) => {
+        $crate::collections::try_format(&$paving, ::std::format_args!($($arg)*))
+    };
+}
@@ -0,0 +1,267 @@
+use std::{
+    alloc::Layout,
+    ptr::{drop_in_place, NonNull},
+    slice,
+};
+
+use crate::{metadata::Metadata, Bump, NewBumpError};
+
+/// A type whose values can be stored field by field in a
+/// [`ColumnarPaving`].
+///
+/// This trait is meant to be derived with `#[derive(Columnar)]`,
+/// available with the `derive` feature.
+pub trait Columnar: Sized {
+    /// The columns storing the fields of the values
+    type Columns;
+
+    /// Try to create empty columns, backed by bumps holding
+    /// `rows_per_chunk` values each.
+    fn try_columns(rows_per_chunk: usize) -> Result<Self::Columns, NewBumpError>;
+
+    /// Try to append a value to the columns, one field per column.
+    ///
+    /// Fails, leaving the columns unchanged, if no bump can be created
+    /// for one of the columns.
+    fn try_push(columns: &mut Self::Columns, value: Self) -> Result<(), Self>;
+}
+
+/// A handle to a row of a [`ColumnarPaving`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Row {
+    index: usize,
+}
+
+impl Row {
+    /// The position of the row in its paving
+    pub fn index(self) -> usize {
+        self.index
+    }
+}
+
+// A run of contiguous values of a column, claimed in its own bump
+struct Segment<F> {
+    _bump: Bump,
+    data: NonNull<F>,
+    len: usize,
+}
+
+impl<F> Segment<F> {
+    fn try_new(layout: Layout) -> Result<Self, NewBumpError> {
+        let bump = Bump::try_new(layout.size().max(1), layout.align())?;
+        let data = bump.claim(layout).ok_or(NewBumpError::InvalidLayout)?;
+        // Safety:
+        // The segment holds the bump, which keeps the chunk alive, so the
+        // count taken by the claim is not needed.
+        unsafe { Metadata::decrement_and_drop(bump.metadata()) };
+        Ok(Self {
+            _bump: bump,
+            data: data.cast(),
+            len: 0,
+        })
+    }
+
+    fn as_slice(&self) -> &[F] {
+        // Safety: the first len values of the segment are initialized
+        unsafe { slice::from_raw_parts(self.data.as_ptr(), self.len) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [F] {
+        // Safety: the first len values of the segment are initialized
+        unsafe { slice::from_raw_parts_mut(self.data.as_ptr(), self.len) }
+    }
+}
+
+impl<F> Drop for Segment<F> {
+    fn drop(&mut self) {
+        // Safety: the values are initialized and never used again
+        unsafe { drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+/// The values of one field of a [`ColumnarPaving`], stored
+/// contiguously in a stream of bumps of their own.
+pub struct Column<F> {
+    layout: Layout,
+    rows_per_chunk: usize,
+    segments: Vec<Segment<F>>,
+}
+
+impl<F> Column<F> {
+    /// Try to create an empty column, backed by bumps holding
+    /// `rows_per_chunk` values each.
+    pub fn try_new(rows_per_chunk: usize) -> Result<Self, NewBumpError> {
+        if rows_per_chunk == 0 {
+            return Err(NewBumpError::NullCapacity);
+        }
+        let layout = Layout::array::<F>(rows_per_chunk).map_err(|_| NewBumpError::InvalidLayout)?;
+        Ok(Self {
+            layout,
+            rows_per_chunk,
+            segments: Vec::new(),
+        })
+    }
+
+    /// Makes room for one more value, returning false if no bump
+    /// can be created.
+    pub fn try_reserve(&mut self) -> bool {
+        if let Some(segment) = self.segments.last() {
+            if segment.len < self.rows_per_chunk {
+                return true;
+            }
+        }
+        match Segment::try_new(self.layout) {
+            Ok(segment) => {
+                self.segments.push(segment);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Appends a value to the column.
+    ///
+    /// # Safety
+    ///
+    /// [`Column::try_reserve`] must have returned true since the last push.
+    #[doc(hidden)]
+    pub unsafe fn push_reserved(&mut self, value: F) {
+        if let Some(segment) = self.segments.last_mut() {
+            // Safety: room was reserved for one more value
+            segment.data.as_ptr().add(segment.len).write(value);
+            segment.len += 1;
+        }
+    }
+
+    /// The number of values of the column
+    pub fn len(&self) -> usize {
+        self.segments.iter().map(|segment| segment.len).sum()
+    }
+
+    /// Whether the column holds no value
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The value of the column in the given row
+    pub fn get(&self, row: Row) -> Option<&F> {
+        let segment = self.segments.get(row.index / self.rows_per_chunk)?;
+        segment.as_slice().get(row.index % self.rows_per_chunk)
+    }
+
+    /// The value of the column in the given row
+    pub fn get_mut(&mut self, row: Row) -> Option<&mut F> {
+        let segment = self.segments.get_mut(row.index / self.rows_per_chunk)?;
+        segment
+            .as_mut_slice()
+            .get_mut(row.index % self.rows_per_chunk)
+    }
+
+    /// The contiguous runs of values of the column, in row order
+    pub fn slices(&self) -> impl Iterator<Item = &[F]> {
+        self.segments.iter().map(Segment::as_slice)
+    }
+
+    /// The contiguous runs of values of the column, in row order
+    pub fn slices_mut(&mut self) -> impl Iterator<Item = &mut [F]> {
+        self.segments.iter_mut().map(Segment::as_mut_slice)
+    }
+
+    /// Iterates over the values of the column, in row order
+    pub fn iter(&self) -> impl Iterator<Item = &F> {
+        self.slices().flatten()
+    }
+
+    /// Iterates over the values of the column, in row order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut F> {
+        self.slices_mut().flatten()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<F> Column<F> {
+    /// Iterates in parallel over the values of the column
+    pub fn par_iter(&self) -> impl rayon::iter::ParallelIterator<Item = &F>
+    where
+        F: Sync,
+    {
+        use rayon::prelude::*;
+        self.slices()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .flat_map(|slice| slice.par_iter())
+    }
+
+    /// Iterates in parallel over the values of the column
+    pub fn par_iter_mut(&mut self) -> impl rayon::iter::ParallelIterator<Item = &mut F>
+    where
+        F: Send,
+    {
+        use rayon::prelude::*;
+        self.slices_mut()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .flat_map(|slice| slice.par_iter_mut())
+    }
+}
+
+/// A structure-of-arrays paving, storing each field of its
+/// values in a separate [`Column`].
+///
+/// Values are appended as rows, and addressed with [`Row`] handles.
+pub struct ColumnarPaving<T: Columnar> {
+    columns: T::Columns,
+    len: usize,
+}
+
+impl<T: Columnar> ColumnarPaving<T> {
+    /// Try to create a new columnar paving, whose columns will be
+    /// backed by bumps holding `rows_per_chunk` values each.
+    pub fn try_new(rows_per_chunk: usize) -> Result<Self, NewBumpError> {
+        Ok(Self {
+            columns: T::try_columns(rows_per_chunk)?,
+            len: 0,
+        })
+    }
+
+    /// Creates a new columnar paving.
+    ///
+    /// See [`ColumnarPaving::try_new`].
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(rows_per_chunk: usize) -> Self {
+        Self::try_new(rows_per_chunk).unwrap_or_else(|err| err.panic())
+    }
+
+    /// Try to append a row to the paving.
+    ///
+    /// Fails if no bump can be created for one of the columns.
+    pub fn try_push(&mut self, value: T) -> Result<Row, T> {
+        T::try_push(&mut self.columns, value)?;
+        let row = Row { index: self.len };
+        self.len += 1;
+        Ok(row)
+    }
+
+    /// The columns of the paving
+    pub fn columns(&self) -> &T::Columns {
+        &self.columns
+    }
+
+    /// The columns of the paving
+    pub fn columns_mut(&mut self) -> &mut T::Columns {
+        &mut self.columns
+    }
+
+    /// The number of rows of the paving
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the paving has no row
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
@@ -0,0 +1,32 @@
+use std::cell::Cell;
+
+// The allocation failures injected in a paving
+#[derive(Default)]
+pub(crate) struct FailPoints {
+    // Fail every nth allocation, never if 0
+    pub(crate) every: usize,
+    // Fail allocations exceeding this number of bytes in total
+    pub(crate) budget: Option<usize>,
+    allocations: Cell<usize>,
+    spent: Cell<usize>,
+}
+
+impl FailPoints {
+    // Accounts for an allocation of the given size,
+    // returning true if it must fail
+    pub(crate) fn should_fail(&self, size: usize) -> bool {
+        let allocations = self.allocations.get().wrapping_add(1);
+        self.allocations.set(allocations);
+        if self.every != 0 && allocations.is_multiple_of(self.every) {
+            return true;
+        }
+        if let Some(budget) = self.budget {
+            let spent = self.spent.get().saturating_add(size);
+            if spent > budget {
+                return true;
+            }
+            self.spent.set(spent);
+        }
+        false
+    }
+}
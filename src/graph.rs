@@ -0,0 +1,159 @@
+//! Graphs stored in arenas.
+//!
+//! With the `petgraph` feature, [`try_alloc_weights`] moves the weights of
+//! a `petgraph` graph into a paving, and [`Csr::to_petgraph`] makes the
+//! algorithms of `petgraph` available for [`Csr`] graphs.
+
+use std::alloc::Layout;
+
+#[cfg(feature = "petgraph")]
+use crate::RcBumpMember;
+use crate::{BumpMember, Paving};
+
+/// A builder collecting the edges of a directed graph, to
+/// store them as a [`Csr`].
+///
+/// Nodes are identified by consecutive integers starting at 0.
+#[derive(Debug, Clone, Default)]
+pub struct CsrBuilder {
+    node_count: u32,
+    edges: Vec<(u32, u32)>,
+}
+
+impl CsrBuilder {
+    /// Creates a builder for a graph with `node_count` nodes and no edge.
+    ///
+    /// Nodes are added as needed when adding edges.
+    pub fn new(node_count: u32) -> Self {
+        Self {
+            node_count,
+            edges: Vec::new(),
+        }
+    }
+
+    /// Adds an edge from `source` to `target`
+    pub fn add_edge(&mut self, source: u32, target: u32) -> &mut Self {
+        self.node_count = self.node_count.max(source.max(target).saturating_add(1));
+        self.edges.push((source, target));
+        self
+    }
+
+    /// Try to store the graph in a paving.
+    ///
+    /// Both arrays of the graph are allocated next to each other in the same
+    /// bump, so this fails if they do not fit in one.
+    pub fn try_build(&self, paving: &Paving) -> Option<Csr> {
+        let mut offsets = vec![0_usize; self.node_count as usize + 1];
+        for &(source, _) in &self.edges {
+            if let Some(offset) = offsets.get_mut(source as usize + 1) {
+                *offset += 1;
+            }
+        }
+        let mut total = 0;
+        for offset in &mut offsets {
+            total += *offset;
+            *offset = total;
+        }
+        // Sorting by source keeps the targets of each node in insertion order
+        let mut edges = self.edges.clone();
+        edges.sort_by_key(|&(source, _)| source);
+        let targets: Vec<u32> = edges.into_iter().map(|(_, target)| target).collect();
+
+        let layout = Layout::array::<usize>(offsets.len())
+            .and_then(|layout| layout.extend(Layout::array::<u32>(targets.len())?))
+            .ok()?
+            .0;
+        paving
+            .try_alloc_inner(layout, (), |bump, ()| {
+                let offsets = bump.try_alloc_slice_copy(&offsets).ok_or(())?;
+                let targets = bump.try_alloc_slice_copy(&targets).ok_or(())?;
+                Ok(Csr { offsets, targets })
+            })
+            .ok()
+    }
+}
+
+/// A directed graph in compressed sparse row format, living in a paving.
+///
+/// The targets of the edges starting from node `n` are
+/// `targets()[offsets()[n]..offsets()[n + 1]]`.
+pub struct Csr {
+    offsets: BumpMember<[usize]>,
+    targets: BumpMember<[u32]>,
+}
+
+impl Csr {
+    /// The number of nodes of the graph
+    pub fn node_count(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// The number of edges of the graph
+    pub fn edge_count(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// The offsets of the targets of each node, followed by the number of edges
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// The targets of the edges, grouped by source
+    pub fn targets(&self) -> &[u32] {
+        &self.targets
+    }
+
+    /// The targets of the edges starting from `node`, or None if there is
+    /// no such node.
+    pub fn neighbors(&self, node: u32) -> Option<&[u32]> {
+        let start = *self.offsets.get(node as usize)?;
+        let end = *self.offsets.get(node as usize + 1)?;
+        self.targets.get(start..end)
+    }
+}
+
+#[cfg(feature = "petgraph")]
+impl Csr {
+    /// Converts the graph into a `petgraph` graph, whose node indices are
+    /// the nodes of this graph.
+    pub fn to_petgraph(&self) -> petgraph::graph::DiGraph<(), ()> {
+        let edges = (0..self.node_count() as u32).flat_map(|source| {
+            self.neighbors(source)
+                .unwrap_or_default()
+                .iter()
+                .map(move |&target| (source, target))
+        });
+        let mut graph =
+            petgraph::graph::DiGraph::with_capacity(self.node_count(), self.edge_count());
+        for _ in 0..self.node_count() {
+            graph.add_node(());
+        }
+        graph.extend_with_edges(edges);
+        graph
+    }
+}
+
+/// Try to move the weights of a `petgraph` graph into a paving, keeping
+/// its structure and indices.
+///
+/// Fails if a weight cannot be allocated.
+#[cfg(feature = "petgraph")]
+pub fn try_alloc_weights<N, E, Ty, Ix>(
+    paving: &Paving,
+    graph: petgraph::Graph<N, E, Ty, Ix>,
+) -> Option<petgraph::Graph<RcBumpMember<N>, RcBumpMember<E>, Ty, Ix>>
+where
+    Ty: petgraph::EdgeType,
+    Ix: petgraph::graph::IndexType,
+{
+    let (nodes, edges) = graph.into_nodes_edges();
+    let mut graph = petgraph::Graph::with_capacity(nodes.len(), edges.len());
+    for node in nodes {
+        graph.add_node(paving.try_alloc_rc(node.weight).ok()?);
+    }
+    for edge in edges {
+        let (source, target) = (edge.source(), edge.target());
+        graph.add_edge(source, target, paving.try_alloc_rc(edge.weight).ok()?);
+    }
+    Some(graph)
+}
@@ -0,0 +1,101 @@
+use std::{
+    borrow::Borrow,
+    cell::RefCell,
+    collections::HashSet,
+    hash::{Hash, Hasher},
+};
+
+use crate::{NewBumpError, Paving, RcBumpMember};
+
+// A member hashed and compared through its pointee
+struct Interned<T>(RcBumpMember<T>);
+
+impl<T: Hash> Hash for Interned<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (*self.0).hash(state)
+    }
+}
+
+impl<T: PartialEq> PartialEq for Interned<T> {
+    fn eq(&self, other: &Self) -> bool {
+        *self.0 == *other.0
+    }
+}
+
+impl<T: Eq> Eq for Interned<T> {}
+
+impl<T> Borrow<T> for Interned<T> {
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
+
+/// A paving deduplicating the values allocated into it.
+///
+/// Interning a value equal to a previously interned one returns
+/// a member sharing the existing allocation.
+/// Interned values are kept alive as long as the `HashConsPaving`.
+pub struct HashConsPaving<T> {
+    paving: Paving,
+    interned: RefCell<HashSet<Interned<T>>>,
+}
+
+impl<T: Eq + Hash> HashConsPaving<T> {
+    /// Try to create a new hash-consing paving, whose backing bumps will have
+    /// the corresponding capacity and align.
+    ///
+    /// See [`Bump::try_new`](`crate::Bump::try_new`).
+    pub fn try_new(capacity: usize, align: usize) -> Result<Self, NewBumpError> {
+        Ok(Self {
+            paving: Paving::try_new(capacity, align)?,
+            interned: RefCell::new(HashSet::new()),
+        })
+    }
+
+    /// Creates a new hash-consing paving, whose backing bumps will have
+    /// the corresponding capacity and align.
+    ///
+    /// See [`Bump::new`](`crate::Bump::new`).
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(capacity: usize, align: usize) -> Self {
+        Self::try_new(capacity, align).unwrap_or_else(|err| err.panic())
+    }
+
+    /// Try to intern a value, returning the existing member if an
+    /// equal value was already interned.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the object, or if called from the `Hash` or `Eq` implementation of `T`.
+    pub fn try_intern(&self, value: T) -> Result<RcBumpMember<T>, T> {
+        let Ok(mut interned) = self.interned.try_borrow_mut() else {
+            return Err(value);
+        };
+        if let Some(Interned(member)) = interned.get(&value) {
+            return Ok(member.clone());
+        }
+        let member = self.paving.try_alloc_rc(value)?;
+        interned.insert(Interned(member.clone()));
+        Ok(member)
+    }
+
+    /// Returns the member equal to `value`, if it was interned
+    pub fn get(&self, value: &T) -> Option<RcBumpMember<T>> {
+        let interned = self.interned.try_borrow().ok()?;
+        interned.get(value).map(|Interned(member)| member.clone())
+    }
+
+    /// Returns the number of distinct values interned
+    pub fn len(&self) -> usize {
+        self.interned
+            .try_borrow()
+            .map_or(0, |interned| interned.len())
+    }
+
+    /// Returns true if no value was interned
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,150 @@
+use std::{
+    alloc::Layout,
+    cell::Cell,
+    fmt,
+    ops::Deref,
+    ptr::{drop_in_place, NonNull},
+};
+
+use crate::{bump::RawBumpMember, member::Sealed, metadata::Metadata, Bump, Paving};
+
+/// The reference count hosted by an [`IntrusiveCount`] value.
+///
+/// It can only be read and modified by this crate, so that safe code
+/// cannot tamper with the count of a shared value.
+#[derive(Default)]
+pub struct IntrusiveCounter(Cell<usize>);
+
+impl IntrusiveCounter {
+    /// Creates a counter, to be set once its value is allocated
+    pub const fn new() -> Self {
+        Self(Cell::new(0))
+    }
+
+    /// The number of shared members pointing to the value
+    pub fn get(&self) -> usize {
+        self.0.get()
+    }
+}
+
+impl fmt::Debug for IntrusiveCounter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntrusiveCounter")
+            .field(&self.get())
+            .finish()
+    }
+}
+
+/// Types hosting the reference count of their shared members themselves.
+///
+/// Allocating such a type with [`Bump::try_alloc_rc_intrusive`] produces
+/// shared members without any extra header, the count living in the value.
+///
+/// # Safety
+///
+/// `count` must always return the same counter, stored in the value
+/// itself rather than behind some interior mutability which would allow
+/// replacing it.
+pub unsafe trait IntrusiveCount {
+    /// The counter of the number of shared members pointing to this value
+    fn count(&self) -> &IntrusiveCounter;
+}
+
+/// A pointer to a [`Bump`] offering shared ownership of
+/// the pointed object, whose reference count is hosted by the object itself.
+///
+/// See [`IntrusiveCount`].
+pub struct IntrusiveRcBumpMember<T: IntrusiveCount> {
+    metadata: NonNull<Metadata>,
+    data: NonNull<T>,
+}
+
+impl<T: IntrusiveCount> IntrusiveRcBumpMember<T> {
+    /// Returns a handle to the bump this member lives in,
+    /// to allocate objects next to it.
+    pub fn arena(&self) -> Bump {
+        Bump::of_member(self)
+    }
+}
+
+impl<T: IntrusiveCount> Deref for IntrusiveRcBumpMember<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // Safety: self contains a valid data entry
+        unsafe { self.data.as_ref() }
+    }
+}
+
+impl<T: IntrusiveCount> Clone for IntrusiveRcBumpMember<T> {
+    fn clone(&self) -> Self {
+        let count = &self.count().0;
+        count.set(count.get() + 1);
+        Self {
+            metadata: self.metadata,
+            data: self.data,
+        }
+    }
+}
+
+impl<T: IntrusiveCount> Sealed for IntrusiveRcBumpMember<T> {
+    fn metadata(&self) -> NonNull<Metadata> {
+        self.metadata
+    }
+
+    #[cfg(feature = "provenance")]
+    fn data(&self) -> NonNull<u8> {
+        self.data.cast()
+    }
+}
+
+impl<T: IntrusiveCount> Drop for IntrusiveRcBumpMember<T> {
+    fn drop(&mut self) {
+        let count = &self.count().0;
+        count.set(count.get() - 1);
+        if count.get() == 0 {
+            // Safety:
+            // We were the last pointer to the value, which is valid
+            unsafe { drop_in_place(self.data.as_ptr()) };
+            // Safety: the value was dropped
+            #[cfg(feature = "zeroize")]
+            unsafe {
+                crate::sensitive::wipe_value(self.metadata, self.data)
+            };
+            // Safety:
+            // No other reference to metadata currently exists
+            // (only pointers)
+            unsafe { Metadata::decrement_and_drop(self.metadata) };
+        }
+    }
+}
+
+impl Bump {
+    /// Try to allocate an object hosting its own reference count,
+    /// with shared ownership in the bump.
+    ///
+    /// The count of `value` is reset to one.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_rc_intrusive<T: IntrusiveCount>(
+        &self,
+        value: T,
+    ) -> Result<IntrusiveRcBumpMember<T>, T> {
+        value.count().0.set(1);
+        let RawBumpMember { metadata, data } = self.try_alloc_inner(value)?;
+        Ok(IntrusiveRcBumpMember { metadata, data })
+    }
+}
+
+impl Paving {
+    /// Try to allocate an object hosting its own reference count,
+    /// with shared ownership in the paving.
+    ///
+    /// See [`Bump::try_alloc_rc_intrusive`].
+    pub fn try_alloc_rc_intrusive<T: IntrusiveCount>(
+        &self,
+        value: T,
+    ) -> Result<IntrusiveRcBumpMember<T>, T> {
+        self.try_alloc_inner(Layout::new::<T>(), value, Bump::try_alloc_rc_intrusive)
+    }
+}
@@ -5,10 +5,60 @@
     clippy::multiple_unsafe_ops_per_block
 )]
 #![warn(clippy::cast_lossless)]
+#![cfg_attr(feature = "nightly", feature(coerce_unsized, unsize))]
+#![cfg_attr(
+    all(feature = "panic_free", not(test)),
+    deny(
+        clippy::panic,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::indexing_slicing,
+        clippy::unreachable,
+        clippy::todo,
+        clippy::unimplemented
+    )
+)]
 
 //! This crate offers fast and locality-aware allocation
 //! similar to bumpalo but without using lifetimes, relying
 //! instead on reference counting.
+//!
+//! # Features
+//!
+//! - `mlock`: allows locking the memory of bumps in RAM.
+//! - `mprotect`: allows making the memory of bumps read-only.
+//! - `zeroize`: allows wiping the memory of bumps holding sensitive data.
+//! - `derive`: provides `#[derive(Columnar)]`, see [`ColumnarPaving`].
+//! - `rayon`: allows iterating in parallel over the columns of a [`ColumnarPaving`].
+//! - `serde`: allows serializing [`StatsSnapshot`] and members, and deserializing
+//!   them straight into a [`Paving`] with [`Paving::seed`].
+//! - `failpoints`: allows making allocations in a [`Paving`] fail
+//!   deterministically, to test the handling of allocation failures.
+//! - `registry`: allows naming pavings, and reporting the memory they hold
+//!   with `report`.
+//! - `provenance`: allows finding where members live in the arena,
+//!   for debugging purposes.
+//! - `allocator-api2`: implements `allocator_api2::alloc::Allocator` for [`Bump`]
+//!   and [`Paving`], allowing collections to be stored in them.
+//! - `petgraph`: allows storing the weights of `petgraph` graphs in a
+//!   [`Paving`], see [`graph`].
+//! - `raw`: allows converting members into raw pointers and back, keeping
+//!   track of the address of the chunks holding such values to find them
+//!   again.
+//! - `rkyv`: allows archiving members with `rkyv`, sharing values between
+//!   [`RcBumpMember`]s, and accessing archives in place from a [`Paving`].
+//! - `nightly`: allows coercing members into members to unsized types, such as
+//!   `RcBumpMember<dyn Trait>`, like `Rc`. Requires a nightly compiler.
+//! - `panic_free`: deprecates every API which may panic, such as `Bump::new`,
+//!   in favor of their fallible counterparts, such as [`Bump::try_new`], and
+//!   denies the lints of clippy catching panics in the rest of the crate.
+//!   Unlike other APIs, trait implementations which may panic, such as
+//!   `Clone` for [`BumpMember`], cannot be flagged.
+
+// Lets the code generated by the derive macros refer to this crate
+extern crate self as rc_bump;
+
+mod metadata;
 
 mod bump;
 pub use bump::*;
@@ -16,14 +66,159 @@ pub use bump::*;
 mod paving;
 pub use paving::*;
 
+mod builder;
+pub use builder::PavingBuilder;
+
+mod adaptive;
+
+#[cfg(feature = "registry")]
+mod registry;
+#[cfg(feature = "registry")]
+pub use registry::{report, ArenaReport};
+
+#[cfg(feature = "failpoints")]
+mod failpoints;
+
+mod chunks;
+pub use chunks::{ChunkInfo, StatsSnapshot};
+
+mod member;
+pub use member::{same_bump, Member};
+
+mod traits;
+
+mod project;
+
+#[cfg(feature = "rkyv")]
+mod archive;
+
+#[cfg(feature = "serde")]
+mod serialize;
+pub use project::{MappedBumpMember, MappedRcBumpMember};
+#[cfg(feature = "serde")]
+pub use serialize::{DeserializeIn, PavingSeed};
+
+mod arc;
+pub use arc::ArcBumpMember;
+
+mod sync_bump;
+pub use sync_bump::SyncBump;
+
+mod sync_paving;
+pub use sync_paving::SyncPaving;
+
+mod sharded_paving;
+pub use sharded_paving::{Shard, ShardedPaving};
+
+#[cfg(feature = "provenance")]
+mod provenance;
+#[cfg(feature = "provenance")]
+pub use provenance::Provenance;
+
+#[cfg(feature = "mlock")]
+mod mlock;
+
+#[cfg(feature = "mprotect")]
+mod mprotect;
+
+#[cfg(feature = "zeroize")]
+mod sensitive;
+#[cfg(feature = "zeroize")]
+pub use sensitive::Sensitive;
+
+mod split;
+
+mod pool;
+pub use pool::ChunkPool;
+
+mod locality;
+pub use locality::{locality_report, LocalityReport, Trace, Tracer};
+
+mod columnar;
+pub use columnar::{Column, Columnar, ColumnarPaving, Row};
+#[cfg(feature = "derive")]
+pub use rc_bump_derive::Columnar;
+
+#[cfg(feature = "allocator-api2")]
+mod allocator;
+
+#[cfg(feature = "nightly")]
+mod coerce;
+
+#[cfg(feature = "raw")]
+mod raw;
+
 mod mixed_paving;
 pub use mixed_paving::*;
 
+mod segmented_paving;
+pub use segmented_paving::*;
+
+mod self_ref;
+pub use self_ref::*;
+
+mod buffer;
+pub use buffer::*;
+
+mod hash_cons;
+pub use hash_cons::*;
+
+mod intrusive;
+pub use intrusive::*;
+
+mod persist;
+pub use persist::{Pod, PodKey, PodPaving, PortablePod};
+
+mod rel;
+pub use rel::RelMember;
+
+mod raw_alloc;
+pub use raw_alloc::RawAlloc;
+
+mod scoped;
+pub use scoped::{BumpGuard, PavingGuard, ScopedMember};
+
+mod any_map;
+pub use any_map::BumpAnyMap;
+
+mod memo;
+pub use memo::MemoCache;
+
+mod weak_cache;
+pub use weak_cache::WeakCache;
+
+mod log_buffer;
+pub use log_buffer::BumpLogBuffer;
+
+mod prefix_interner;
+pub use prefix_interner::{PrefixInterner, PrefixKey};
+
+pub mod channel;
+
+pub mod collections;
+
+pub mod graph;
+
+pub mod prelude;
+
 #[cfg(test)]
+#[cfg_attr(feature = "panic_free", allow(deprecated))]
 mod test {
     use std::mem::{align_of, size_of};
 
-    use crate::{Bump, Paving};
+    use std::{
+        cell::{Cell, RefCell},
+        rc::Rc,
+        sync::Arc,
+    };
+
+    use crate::{
+        locality_report, same_bump, Bump, BumpMember, ChunkPool, HashConsPaving, IntrusiveCount,
+        MappedBumpMember, NewBumpError, Paving, PrefixInterner, RcBumpMember, RetirementPolicy,
+        ScopedMember, Trace, Tracer,
+    };
+
+    crate::borrow_family!(Words = for<'a> Vec<&'a str>);
 
     #[test]
     fn test_creation_bump() {
@@ -58,4 +253,2331 @@ mod test {
             assert_eq!(*bump_member2, 456);
         }
     }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(Bump::try_new(0, 8).err(), Some(NewBumpError::NullCapacity));
+        assert_eq!(
+            Paving::try_new(8, 3).err(),
+            Some(NewBumpError::InvalidLayout)
+        );
+        assert!(Paving::try_new(8, 8).is_ok());
+    }
+
+    #[test]
+    fn test_self_ref() {
+        let paving = Paving::new(256, align_of::<u64>());
+        let mut words = paving
+            .try_alloc_self_ref::<_, Words>(String::from("hello arena world"), |s| {
+                s.split(' ').collect()
+            })
+            .ok()
+            .unwrap();
+        drop(paving);
+        words.with_dependent_mut(|owner, words| words.push(&owner[..1]));
+        words.with_dependent(|_, words| assert_eq!(words, &["hello", "arena", "world", "h"]));
+        assert_eq!(&*words.into_owner(), "hello arena world");
+    }
+
+    #[test]
+    fn test_bump_str_views() {
+        let tokens: Vec<_> = {
+            let paving = Paving::new(64, 1);
+            let text = paving.try_alloc_bump_str("let x = 42;").unwrap();
+            text.split(' ').map(|tok| text.slice_ref(tok)).collect()
+        };
+        let tokens: Vec<&str> = tokens.iter().map(|tok| &**tok).collect();
+        assert_eq!(tokens, ["let", "x", "=", "42;"]);
+    }
+
+    #[test]
+    fn test_hash_cons() {
+        let types = HashConsPaving::new(256, align_of::<String>());
+        let int = types.try_intern(String::from("int")).unwrap();
+        let float = types.try_intern(String::from("float")).unwrap();
+        let int_again = types.try_intern(String::from("int")).unwrap();
+        assert!(std::ptr::eq(&*int, &*int_again));
+        assert!(!std::ptr::eq(&*int, &*float));
+        assert_eq!(types.len(), 2);
+    }
+
+    #[test]
+    fn test_paving_chunks() {
+        let paving = Paving::new(4 * size_of::<u64>(), align_of::<u64>());
+        let first = paving.try_alloc(1_u64).unwrap();
+        for i in 0..4_u64 {
+            paving.try_alloc(i).unwrap();
+        }
+        let used: Vec<_> = paving.chunks().map(|chunk| chunk.used).collect();
+        assert_eq!(used, [0..size_of::<u64>(), 0..4 * size_of::<u64>()]);
+        drop(first);
+        assert_eq!(paving.chunks().count(), 1);
+    }
+
+    #[test]
+    fn test_alloc_near() {
+        let paving = Paving::new(size_of::<[u64; 8]>(), align_of::<u64>());
+        let root = paving.try_alloc_rc(0_u64).unwrap();
+        paving.try_alloc([0_u64; 4]).unwrap();
+        let other_chunk = paving.try_alloc([0_u64; 4]).unwrap();
+        let child = paving.try_alloc_near(&root, 1_u64).unwrap();
+        let root_addr = &*root as *const u64 as usize;
+        let child_addr = &*child as *const u64 as usize;
+        let other_addr = &*other_chunk as *const [u64; 4] as usize;
+        assert_eq!(child_addr - root_addr, 5 * size_of::<u64>());
+        assert!(other_addr.abs_diff(child_addr) >= size_of::<[u64; 8]>());
+    }
+
+    #[test]
+    fn test_member_arena() {
+        let root = {
+            let bump = Bump::new(2 * size_of::<u64>(), align_of::<u64>());
+            bump.try_alloc(0_u64).unwrap()
+        };
+        let arena = root.arena();
+        let sibling = arena.clone().try_alloc(1_u64).unwrap();
+        assert_eq!(
+            &*sibling as *const u64,
+            (&*root as *const u64).wrapping_add(1)
+        );
+        assert!(arena.try_alloc(2_u64).is_err());
+        assert!(arena.contains(&*sibling));
+        assert!(same_bump(&root, &sibling));
+        let elsewhere = Bump::new(8, 8).try_alloc(3_u64).unwrap();
+        assert!(!arena.contains(&*elsewhere));
+        assert!(!same_bump(&root, &elsewhere));
+    }
+
+    #[cfg(feature = "mlock")]
+    #[test]
+    fn test_locked_paving() {
+        let paving = Paving::new(4 * size_of::<u64>(), align_of::<u64>()).with_locked_memory();
+        let members: Vec<_> = (0..16_u64)
+            .map(|i| paving.try_alloc_rc(i).unwrap())
+            .collect();
+        drop(paving);
+        // Locking can be denied by the environment, the bump then stays unlocked
+        let bump = members[15].arena();
+        assert_eq!(bump.lock_memory(), bump.is_memory_locked());
+        assert_eq!(*members[15], 15);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_sensitive_paving() {
+        let paving = Paving::new(4 * size_of::<u64>(), align_of::<u64>()).with_sensitive_memory();
+        let keep = paving.try_alloc(1_u64).unwrap();
+        let secret = paving.try_alloc(0xdead_beef_u64).unwrap();
+        drop(secret);
+        let base = paving.chunks().next().unwrap().base;
+        // Safety: the bump is still alive, and secret was at its second slot
+        assert_eq!(unsafe { base.cast::<u64>().wrapping_add(1).read() }, 0);
+        assert_eq!(*keep, 1);
+    }
+
+    #[test]
+    fn test_intrusive_count() {
+        use crate::IntrusiveCounter;
+
+        struct Node {
+            count: IntrusiveCounter,
+            dropped: Rc<Cell<bool>>,
+        }
+        // Safety: count is a field of the node
+        unsafe impl IntrusiveCount for Node {
+            fn count(&self) -> &IntrusiveCounter {
+                &self.count
+            }
+        }
+        impl Drop for Node {
+            fn drop(&mut self) {
+                self.dropped.set(true)
+            }
+        }
+
+        let dropped = Rc::new(Cell::new(false));
+        let paving = Paving::new(4 * size_of::<Node>(), align_of::<Node>());
+        let node = Node {
+            count: IntrusiveCounter::new(),
+            dropped: dropped.clone(),
+        };
+        let a = paving.try_alloc_rc_intrusive(node).ok().unwrap();
+        let b = a.clone();
+        assert_eq!(b.count.get(), 2);
+        drop(a);
+        assert!(!dropped.get());
+        drop(b);
+        assert!(dropped.get());
+    }
+
+    #[test]
+    fn test_split() {
+        let bump = Bump::new(1024, 8);
+        let regions = bump.split(&[64, 64]).unwrap();
+        let (a, b) = (&regions[0], &regions[1]);
+        let a1 = a.try_alloc(1_u64).ok().unwrap();
+        let b1 = b.try_alloc(2_u64).ok().unwrap();
+        let a2 = a.try_alloc(3_u64).ok().unwrap();
+        assert_eq!(
+            &*a2 as *const u64 as usize - &*a1 as *const u64 as usize,
+            size_of::<u64>()
+        );
+        assert!(a.contains(&*b1));
+        assert!(b.try_alloc([0_u8; 65]).is_err());
+        assert!(bump.split(&[1024]).is_none());
+        let rest = bump.split_off_remaining().unwrap();
+        assert!(bump.try_alloc(0_u8).is_err());
+        let c = rest.try_alloc(4_u64).ok().unwrap();
+        drop((bump, regions, rest));
+        assert_eq!((*a1, *b1, *a2, *c), (1, 2, 3, 4));
+    }
+
+    #[test]
+    fn test_chunk_pool() {
+        let pool = ChunkPool::new(64, 8, 2);
+        assert_eq!(pool.available(), 2);
+        let bases: Vec<_> = {
+            let paving = pool.paving();
+            let a = paving.try_alloc([1_u64; 4]).ok().unwrap();
+            let b = paving.try_alloc([2_u64; 4]).ok().unwrap();
+            let c = paving.try_alloc([3_u64; 4]).ok().unwrap();
+            assert_eq!(pool.available(), 0);
+            assert_eq!((a[0], b[0], c[0]), (1, 2, 3));
+            paving.chunks().map(|chunk| chunk.base).collect()
+        };
+        assert_eq!(pool.available(), 2);
+        let paving = pool.paving();
+        assert!(bases.contains(&paving.chunks().next().unwrap().base));
+        let _members: Vec<_> = (0..5_u64)
+            .map(|i| paving.try_alloc([i; 4]).ok().unwrap())
+            .collect();
+        drop(pool);
+        assert_eq!(paving.chunks().count(), 3);
+    }
+
+    #[test]
+    fn test_locality_report() {
+        struct Node(Option<RcBumpMember<Node>>);
+        impl Trace for Node {
+            fn trace(&self, tracer: &mut Tracer) {
+                if let Some(next) = &self.0 {
+                    tracer.edge(next);
+                }
+            }
+        }
+        let paving = Paving::new(4 * size_of::<Node>(), align_of::<Node>());
+        let mut list = paving.try_alloc_rc(Node(None)).ok().unwrap();
+        for _ in 0..3 {
+            list = paving.try_alloc_rc(Node(Some(list))).ok().unwrap();
+        }
+        let report = locality_report(&list);
+        assert_eq!((report.nodes, report.edges), (4, 3));
+        assert_eq!(report.cross_chunk_edges, 1);
+        assert_eq!(report.cross_chunk_ratio(), 1. / 3.);
+        assert!(report.average_distance() >= size_of::<Node>() as f64);
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_columnar_paving() {
+        use crate::{Columnar, ColumnarPaving};
+
+        #[derive(Columnar)]
+        struct Particle {
+            position: [f32; 2],
+            name: String,
+        }
+        let mut paving = ColumnarPaving::<Particle>::new(2);
+        let rows: Vec<_> = (0..5)
+            .map(|i| {
+                let particle = Particle {
+                    position: [i as f32, 0.],
+                    name: i.to_string(),
+                };
+                paving.try_push(particle).ok().unwrap()
+            })
+            .collect();
+        assert_eq!(paving.len(), 5);
+        let columns = paving.columns_mut();
+        for position in columns.position.iter_mut() {
+            position[1] = 1.;
+        }
+        assert_eq!(columns.position.slices().count(), 3);
+        let first = columns.position.slices().next().unwrap();
+        assert_eq!(first, &[[0., 1.], [1., 1.]]);
+        assert_eq!(columns.name.get(rows[3]).unwrap(), "3");
+        assert_eq!(columns.name.iter().cloned().collect::<String>(), "01234");
+    }
+
+    #[test]
+    fn test_retirement_policy() {
+        let paving =
+            Paving::new(64, 8).with_retirement_policy(RetirementPolicy::MinRemainingBytes(16));
+        let first = paving.try_alloc(0_u64).ok().unwrap();
+        let large = paving.try_alloc([1_u64; 4]).ok().unwrap();
+        assert!(same_bump(&first, &large));
+        // 24 bytes are left, the first bump is kept
+        let large = paving.try_alloc([2_u64; 4]).ok().unwrap();
+        assert!(!same_bump(&first, &large));
+        let small = paving.try_alloc(3_u64).ok().unwrap();
+        assert!(same_bump(&first, &small));
+        let small = paving.try_alloc(4_u64).ok().unwrap();
+        assert!(same_bump(&first, &small));
+        // 8 bytes are left, the first bump is abandoned
+        let large = paving.try_alloc([5_u64; 4]).ok().unwrap();
+        let small = paving.try_alloc(6_u64).ok().unwrap();
+        assert!(same_bump(&large, &small));
+    }
+
+    #[test]
+    fn test_std_conversions() {
+        let paving = Paving::new(1024, 8);
+        let member = paving.try_alloc(vec![1, 2, 3]).ok().unwrap();
+        assert_eq!(*member.into_box(), [1, 2, 3]);
+        let rc = paving.try_alloc_rc(String::from("shared")).ok().unwrap();
+        assert_eq!(*rc.to_rc(), "shared");
+        let arc = rc.to_arc();
+        drop((paving, rc));
+        assert_eq!(std::thread::spawn(move || arc.len()).join().unwrap(), 6);
+    }
+
+    #[test]
+    fn test_stats_snapshot() {
+        let paving = Paving::new(64, 8);
+        let _members: Vec<_> = (0..5_u64)
+            .map(|i| paving.try_alloc([i; 3]).ok().unwrap())
+            .collect();
+        let stats = paving.snapshot();
+        assert_eq!((stats.chunks, stats.capacity, stats.used), (3, 192, 120));
+        assert_eq!(Bump::new(64, 8).snapshot().used, 0);
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_json::to_string(&stats).unwrap(),
+            r#"{"chunks":3,"capacity":192,"used":120}"#
+        );
+    }
+
+    #[test]
+    fn test_adaptive_sizing() {
+        let paving = Paving::new(64, 8).with_adaptive_sizing();
+        let small = paving.try_alloc(0_u64).ok().unwrap();
+        let large = paving.try_alloc([1_u64; 1024]).ok().unwrap();
+        assert!(paving.chunk_capacity() >= 2 * size_of::<[u64; 1024]>());
+        assert!(!same_bump(&small, &large));
+        let fixed = Paving::new(64, 8);
+        fixed.try_alloc([1_u64; 1024]).ok().unwrap();
+        assert_eq!(fixed.chunk_capacity(), 64);
+
+        // Padding does not count in the sizes of the objects
+        let paving = Paving::new(64, 8).with_adaptive_sizing();
+        for _ in 0..2 {
+            let aligned = paving.try_alloc_aligned([0_u8; 8], 8192).unwrap();
+            assert_eq!(BumpMember::as_ptr(&aligned) as *const u8 as usize % 8192, 0);
+        }
+    }
+
+    #[test]
+    fn test_adaptive_running_sizes() {
+        let stats = crate::adaptive::AllocStats::default();
+        for _ in 0..1024 {
+            stats.record(1024);
+        }
+        assert_eq!(stats.capacity(), 64 * 1024);
+        // Older sizes fade away
+        for _ in 0..4096 {
+            stats.record(16);
+        }
+        assert!(stats.capacity() < 8192);
+        // Including the largest one
+        stats.record(1 << 20);
+        for _ in 0..16384 {
+            stats.record(16);
+        }
+        assert!(stats.capacity() < 8192);
+
+        let paving = Paving::builder().adaptive_sizing().build();
+        let large = paving.try_alloc([0_u8; 8192]).unwrap();
+        assert!(paving.chunk_capacity() >= 2 * 8192);
+        assert_eq!(large[0], 0);
+    }
+
+    #[test]
+    fn test_growth_strategy() {
+        use crate::GrowthStrategy;
+
+        let capacities = |strategy| {
+            let paving = Paving::new(64, 8).with_growth_strategy(strategy);
+            let mut capacities = vec![paving.chunk_capacity()];
+            while capacities.len() < 5 {
+                let created = paving.chunks_created();
+                paving.try_alloc([0_u64; 8]).ok().unwrap();
+                if paving.chunks_created() > created {
+                    capacities.push(paving.chunk_capacity());
+                }
+            }
+            capacities
+        };
+        assert_eq!(capacities(GrowthStrategy::Fixed), [64; 5]);
+        assert_eq!(
+            capacities(GrowthStrategy::Doubling(512)),
+            [64, 128, 256, 512, 512]
+        );
+        let geometric = GrowthStrategy::Geometric {
+            factor: 1.5,
+            max: 200,
+        };
+        assert_eq!(capacities(geometric), [64, 96, 144, 200, 200]);
+    }
+
+    #[test]
+    fn test_paving_builder() {
+        use crate::{AllocErrorKind, GrowthStrategy};
+
+        let paving = Paving::builder()
+            .chunk_capacity(64)
+            .align(16)
+            .growth_strategy(GrowthStrategy::Doubling(128))
+            .max_chunks(2)
+            .build();
+        assert_eq!((paving.chunk_capacity(), paving.align()), (64, 16));
+        let first = paving.try_alloc([0_u8; 64]).unwrap();
+        let second = paving.try_alloc([1_u8; 64]).unwrap();
+        assert_eq!(paving.chunk_capacity(), 128);
+        let err = paving.try_alloc_detailed([2_u8; 128]).err().unwrap();
+        assert_eq!(err.kind(), AllocErrorKind::LimitReached);
+        drop(first);
+        assert_eq!(paving.try_alloc([3_u8; 128]).unwrap()[0], 3);
+        drop(second);
+
+        let paving = Paving::builder().chunk_capacity(64).max_bytes(100).build();
+        let err = paving.try_alloc_detailed([0_u8; 72]).err().unwrap();
+        assert_eq!(err.kind(), AllocErrorKind::BudgetExceeded);
+
+        let pool = ChunkPool::new(32, 8, 1);
+        let paving = Paving::builder().pool(pool.clone()).build();
+        assert_eq!(paving.chunk_capacity(), 32);
+        assert_eq!(pool.available(), 0);
+    }
+
+    #[test]
+    fn test_memory_budget() {
+        use crate::AllocErrorKind;
+
+        let paving = Paving::new(64, 8).with_memory_budget(128);
+        let first = paving.try_alloc([0_u8; 64]).unwrap();
+        let second = paving.try_alloc([1_u8; 64]).unwrap();
+        let err = paving.try_alloc_detailed(2_u8).err().unwrap();
+        assert_eq!(err.kind(), AllocErrorKind::BudgetExceeded);
+        assert_eq!(err.into_value(), 2);
+        // Dropping members frees their chunks, and room in the budget
+        drop(first);
+        assert_eq!(*paving.try_alloc(3_u8).unwrap(), 3);
+        assert!(paving.snapshot().capacity <= 128);
+        drop(second);
+    }
+
+    #[test]
+    fn test_paving_reserve() {
+        let paving = Paving::new(64, 8);
+        let first = paving.try_alloc([0_u64; 6]).unwrap();
+        assert!(paving.try_reserve(16).is_ok());
+        assert_eq!(paving.chunks_created(), 1);
+        assert!(paving.try_reserve(32).is_ok());
+        assert_eq!(paving.chunks_created(), 2);
+        let burst: Vec<_> = paving
+            .assert_no_new_chunks(|| (0..4_u64).map(|i| paving.try_alloc(i).unwrap()).collect());
+        assert!(!same_bump(&first, &burst[0]));
+        assert!(same_bump(&burst[0], &burst[3]));
+
+        // Reservations larger than the bumps get a bump of their own size
+        assert!(paving.try_reserve(256).is_ok());
+        let large = paving.assert_no_new_chunks(|| paving.try_alloc([1_u64; 32]).unwrap());
+        assert_eq!(large[31], 1);
+        assert_eq!(paving.chunk_capacity(), 64);
+
+        let pool = ChunkPool::new(64, 8, 0);
+        let err = pool.paving().try_reserve(128).err().unwrap();
+        assert_eq!(err, crate::AllocErrorKind::TooLarge);
+    }
+
+    #[test]
+    fn test_recent_chunks() {
+        let paving = Paving::new(64, 8).with_recent_chunks(2);
+        let first = paving.try_alloc([0_u64; 6]).unwrap();
+        let second = paving.try_alloc([1_u64; 8]).unwrap();
+        assert!(!same_bump(&first, &second));
+        // Fits at the end of the first bump, abandoned by the paving
+        let small = paving.try_alloc(2_u64).unwrap();
+        assert!(same_bump(&first, &small));
+        assert_eq!(paving.chunks_created(), 2);
+
+        let paving = Paving::new(64, 8);
+        let first = paving.try_alloc([0_u64; 6]).unwrap();
+        let _second = paving.try_alloc([1_u64; 8]).unwrap();
+        assert!(!same_bump(&first, &paving.try_alloc(2_u64).unwrap()));
+        assert_eq!(paving.chunks_created(), 3);
+    }
+
+    #[cfg(feature = "failpoints")]
+    #[test]
+    fn test_failpoints() {
+        let paving = Paving::new(1024, 8).with_failure_every(3);
+        let results: Vec<_> = (0..6_u64).map(|i| paving.try_alloc(i).is_ok()).collect();
+        assert_eq!(results, [true, true, false, true, true, false]);
+        let paving = Paving::new(1024, 8).with_byte_budget(20);
+        assert!(paving.try_alloc(0_u64).is_ok());
+        assert!(paving.try_alloc(1_u64).is_ok());
+        assert_eq!(paving.try_alloc(2_u64).err(), Some(2));
+        assert!(paving.try_alloc(3_u32).is_ok());
+
+        // Batches fail as a whole
+        let paving = Paving::new(1024, 8).with_failure_every(3);
+        assert_eq!(paving.try_alloc_iter([0_u64, 1]).unwrap().len(), 2);
+        assert_eq!(paving.try_alloc_iter_rc([2_u64, 3]).err().unwrap(), [2, 3]);
+        let paving = Paving::new(1024, 8).with_byte_budget(20);
+        assert_eq!(
+            paving.try_alloc_iter([0_u64, 1, 2]).err().unwrap(),
+            [0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_required_capacity() {
+        const CAPACITY: usize = crate::required_capacity_for::<u64>(4);
+        let bump = Bump::new(CAPACITY, 1);
+        let _members: Vec<_> = (0..4_u64)
+            .map(|i| bump.try_alloc(i).ok().unwrap())
+            .collect();
+        const RC_CAPACITY: usize = crate::required_capacity_for_rc::<String>(3);
+        let bump = Bump::new(RC_CAPACITY, 1);
+        let _members: Vec<_> = (0..3)
+            .map(|_| bump.try_alloc_rc(String::new()).ok().unwrap())
+            .collect();
+        assert!(RC_CAPACITY > crate::required_capacity_for::<String>(3));
+    }
+
+    #[test]
+    fn test_push_in_place() {
+        let bump = Bump::new(64, 8);
+        let mut slice = bump.try_alloc([0_u64; 0]).ok().unwrap().into_slice();
+        for i in 0..4 {
+            slice.try_push(i).unwrap();
+        }
+        assert_eq!(*slice, [0, 1, 2, 3]);
+        let _other = bump.try_alloc(0_u8).ok().unwrap();
+        assert_eq!(slice.try_push(4), Err(4));
+
+        let mut s = bump.try_alloc_bump_str("hello").unwrap();
+        assert!(s.try_push_str(", world").is_ok());
+        assert_eq!(&*s, "hello, world");
+        let mut view = s.get(7..).unwrap();
+        assert!(view.try_push_str("!").is_ok());
+        assert!(s.try_push_str("?").is_err());
+        assert!(view.try_push_str(&"!".repeat(64)).is_err());
+        assert_eq!((&*s, &*view), ("hello, world", "world!"));
+    }
+
+    #[test]
+    fn test_alloc_policy() {
+        use crate::{AllocPolicy, MixedPaving, OwnedMixedPavingMember, SharedMixedPavingMember};
+
+        let paving = MixedPaving::new(64, 8);
+        let small = paving.alloc_with_policy(0_u64, AllocPolicy::ForceHeap);
+        assert!(matches!(small, OwnedMixedPavingMember::Box(_)));
+        let large = paving.alloc_with_policy([1_u64; 64], AllocPolicy::Auto);
+        assert!(matches!(large, OwnedMixedPavingMember::Box(_)));
+        let large = paving.alloc_with_policy([2_u64; 64], AllocPolicy::ForceBump);
+        assert!(matches!(large, OwnedMixedPavingMember::BumpMember(_)));
+        assert_eq!(large[63], 2);
+        let shared = paving.alloc_rc_with_policy(vec![3; 64], AllocPolicy::ForceBump);
+        assert!(matches!(shared, SharedMixedPavingMember::RcBumpMember(_)));
+    }
+
+    #[test]
+    fn test_spill_threshold() {
+        use crate::{MixedPaving, OwnedMixedPavingMember, SharedMixedPavingMember};
+
+        let paving = MixedPaving::new(64, 8).with_spill_threshold(16);
+        assert!(matches!(
+            paving.alloc([0_u64; 2]),
+            OwnedMixedPavingMember::BumpMember(_)
+        ));
+        assert!(matches!(
+            paving.alloc([0_u64; 3]),
+            OwnedMixedPavingMember::Box(_)
+        ));
+        assert!(matches!(
+            paving.alloc_rc([0_u64; 3]),
+            SharedMixedPavingMember::Rc(_)
+        ));
+
+        // Objects below the threshold get a bump of their own if needed
+        let paving = MixedPaving::new(64, 8).with_spill_threshold(1024);
+        let large = paving.alloc([1_u64; 64]);
+        assert!(matches!(large, OwnedMixedPavingMember::BumpMember(_)));
+        assert_eq!(large[63], 1);
+    }
+
+    #[cfg(feature = "registry")]
+    #[test]
+    fn test_registry() {
+        let find = || {
+            crate::report()
+                .into_iter()
+                .find(|arena| arena.name == "test_registry")
+        };
+        let paving = Paving::new(64, 8).with_name("test_registry");
+        let members: Vec<_> = (0..3_u64)
+            .map(|i| paving.try_alloc([i; 4]).ok().unwrap())
+            .collect();
+        let arena = find().unwrap();
+        assert_eq!(arena.chunks, 2);
+        assert!(arena.bytes >= 128);
+        drop(paving);
+        assert_eq!(find().unwrap().chunks, 2);
+        drop(members);
+        assert_eq!(find(), None);
+    }
+
+    #[cfg(feature = "provenance")]
+    #[test]
+    fn test_provenance() {
+        use crate::Member;
+
+        let bump = Bump::new(64, 8);
+        let a = bump.try_alloc(0_u64).ok().unwrap();
+        let b = bump.try_alloc(1_u64).ok().unwrap();
+        assert_eq!(a.provenance().chunk, bump.chunk_id());
+        assert_eq!(a.provenance().offset, 0);
+        assert_eq!(b.provenance().offset, 8);
+        assert_ne!(Bump::new(64, 8).chunk_id(), bump.chunk_id());
+        let expected = format!("chunk {} at offset 0x8", bump.chunk_id());
+        assert_eq!(b.provenance().to_string(), expected);
+    }
+
+    #[test]
+    fn test_any_map() {
+        let mut map = crate::BumpAnyMap::new(1024, 8);
+        assert_eq!(map.try_insert(1_u64), Ok(None));
+        assert_eq!(map.try_insert(String::from("a")), Ok(None));
+        assert_eq!(map.try_insert(2_u64), Ok(Some(1)));
+        map.get_mut::<String>().unwrap().push('b');
+        assert_eq!(map.get::<String>().unwrap(), "ab");
+        assert_eq!(map.get::<u64>(), Some(&2));
+        assert_eq!(map.get::<u32>(), None);
+        assert_eq!(map.remove::<String>().as_deref(), Some("ab"));
+        assert!(!map.contains::<String>());
+        assert_eq!(map.len(), 1);
+    }
+
+    #[cfg(feature = "mprotect")]
+    #[test]
+    fn test_protect_read_only() {
+        let bump = Bump::new(4 * 4096, 4096);
+        // Safety: nothing is allocated in the bump
+        assert!(!unsafe { bump.protect_read_only() });
+        let table = bump.try_alloc([7_u8; 8192]).ok().unwrap();
+        // Safety: the table is only read while the bump is protected
+        assert!(unsafe { bump.protect_read_only() });
+        assert!(bump.is_protected());
+        assert_eq!(table.iter().map(|&b| b as usize).sum::<usize>(), 7 * 8192);
+        let mut other = bump.try_alloc(0_u64).ok().unwrap();
+        *other += 1;
+        bump.unprotect();
+        assert!(!bump.is_protected());
+        drop((table, other));
+
+        // The pages protected start past the beginning of the bump
+        let bump = Bump::new(4 * 4096, 8);
+        let table = bump.try_alloc([7_u8; 8192]).ok().unwrap();
+        let tail = bump.try_alloc([0_u8; 4096]).ok().unwrap();
+        // Safety: the table and the tail are not written while protected
+        assert!(unsafe { bump.protect_read_only() });
+        let used = bump.used();
+        tail.take();
+        // The tail starts in a read-only page, unless the bump starts a page
+        let page_aligned = (BumpMember::as_ptr(&table) as *const u8 as usize).is_multiple_of(4096);
+        assert_eq!(bump.used() == used, !page_aligned);
+        let mut other = bump.try_alloc(0_u64).ok().unwrap();
+        *other += 1;
+        drop((table, other));
+
+        // Shared values without a header are counted in the metadata of
+        // the bump, which is never protected
+        let bump = Bump::new(4 * 4096, 4096);
+        let table = bump.try_alloc_rc([7_u8; 8192]).ok().unwrap();
+        // Safety: the table has no header, and is only read while protected
+        assert!(unsafe { bump.protect_read_only() });
+        let shared = table.clone();
+        drop(table);
+        assert_eq!(shared[8191], 7);
+        drop(shared);
+
+        let small = Bump::new(64, 8);
+        let _value = small.try_alloc(1_u64).ok().unwrap();
+        // Safety: the value is not written
+        assert!(!unsafe { small.protect_read_only() });
+    }
+
+    #[test]
+    fn test_relocate_into() {
+        let scratch = Paving::new(64, 8);
+        let long_lived = Paving::new(1024, 8);
+        let survivor = scratch.try_alloc(vec![1, 2, 3]).ok().unwrap();
+        let _garbage = scratch.try_alloc(0_u64).ok().unwrap();
+        let survivor = survivor.relocate_into(&long_lived);
+        drop(scratch);
+        assert_eq!(long_lived.chunks().count(), 1);
+        assert_eq!(*survivor, [1, 2, 3]);
+        let large = long_lived.try_alloc([0_u64; 16]).ok().unwrap();
+        let small = Paving::new(64, 8);
+        assert!(!same_bump(
+            &large.relocate_into(&small),
+            &small.try_alloc(0_u8).unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_csr() {
+        use crate::graph::CsrBuilder;
+
+        let paving = Paving::new(1024, 8);
+        let mut builder = CsrBuilder::new(2);
+        builder
+            .add_edge(2, 0)
+            .add_edge(0, 1)
+            .add_edge(2, 1)
+            .add_edge(0, 2);
+        let csr = builder.try_build(&paving).unwrap();
+        assert_eq!((csr.node_count(), csr.edge_count()), (3, 4));
+        assert_eq!(csr.offsets(), [0, 2, 2, 4]);
+        assert_eq!(csr.neighbors(0), Some(&[1, 2][..]));
+        assert_eq!(csr.neighbors(1), Some(&[][..]));
+        assert_eq!(csr.neighbors(2), Some(&[0, 1][..]));
+        assert_eq!(csr.neighbors(3), None);
+        let end = csr.offsets().as_ptr_range().end;
+        assert_eq!(end.cast::<u32>(), csr.targets().as_ptr());
+    }
+
+    #[test]
+    fn test_alloc_iter_rc() {
+        let paving = Paving::new(64, 8);
+        let members = paving
+            .try_alloc_iter_rc((0..20_u32).map(|i| i.to_string()))
+            .unwrap();
+        assert_eq!(members.len(), 20);
+        for (i, member) in members.iter().enumerate() {
+            assert_eq!(**member, i.to_string());
+        }
+        let clone = members[3].clone();
+        drop(members);
+        assert_eq!(*clone, "3");
+
+        let numbers = paving.try_alloc_slice_rc(&[1_u32, 2, 3]).unwrap();
+        assert_eq!(numbers.iter().map(|n| **n).collect::<Vec<_>>(), [1, 2, 3]);
+        assert!(same_bump(&numbers[1], &numbers[2]));
+
+        let large = paving.try_alloc_iter_rc([[0_u8; 72], [1; 72]]).unwrap();
+        assert!(!same_bump(&large[0], &large[1]));
+
+        // The members built before a panic of the iterator are released
+        // along with the counts they hold
+        let paving = Paving::new(256, 8);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            paving.try_alloc_iter_rc((0..10_u32).map(|i| {
+                assert_ne!(i, 2, "Cannot build");
+                i.to_string()
+            }))
+        }));
+        assert!(result.is_err());
+        let after = paving.try_alloc_rc(7.to_string()).unwrap();
+        assert_eq!(*after.clone(), "7");
+    }
+
+    #[test]
+    fn test_alloc_iter() {
+        let paving = Paving::new(64, 8);
+        let members = paving.try_alloc_iter((0..20_u32).map(u64::from)).unwrap();
+        assert_eq!(members.len(), 20);
+        assert!(members.iter().map(|m| **m).eq(0..20_u64));
+        assert!(same_bump(&members[0], &members[7]));
+        assert!(!same_bump(&members[0], &members[8]));
+        assert_eq!(paving.snapshot().used, 20 * 8);
+
+        let large = paving.try_alloc_iter([[0_u8; 72], [1; 72]]).unwrap();
+        assert_eq!(large[1][71], 1);
+
+        // The members built before a panic of the iterator are released
+        // along with the counts they hold
+        let paving = Paving::new(64, 8);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            paving.try_alloc_iter((0..10_u32).map(|i| {
+                assert_ne!(i, 5, "Cannot build");
+                Box::new(u64::from(i))
+            }))
+        }));
+        assert!(result.is_err());
+        let after = paving.try_alloc(Box::new(7_u64)).unwrap();
+        assert_eq!(**after, 7);
+    }
+
+    #[test]
+    fn test_prefix_interner() {
+        let mut interner = PrefixInterner::new(256);
+        let keys: [&[u8]; 5] = [b"/usr/lib", b"/usr/bin", b"/usr", b"/usr/lib64", b""];
+        let handles: Vec<_> = keys
+            .iter()
+            .map(|key| interner.try_intern(key).unwrap())
+            .collect();
+        assert_eq!(interner.len(), 5);
+        for (key, handle) in keys.iter().zip(&handles) {
+            assert_eq!(interner.try_intern(key), Some(*handle));
+            assert_eq!(interner.get(key), Some(*handle));
+            assert_eq!(interner.resolve(*handle).as_deref(), Some(*key));
+        }
+        assert_eq!(interner.len(), 5);
+        assert_eq!(interner.get(b"/us"), None);
+        assert_eq!(interner.get(b"/usr/li"), None);
+        assert_eq!(
+            interner.stored_bytes(),
+            "/usr/lib".len() + "bin".len() + "64".len()
+        );
+    }
+
+    #[test]
+    fn test_log_buffer() {
+        use std::io::Write;
+
+        let mut log = crate::BumpLogBuffer::new(Vec::new(), 8);
+        log.write_record(b"abc").unwrap();
+        log.write_record(b"defg").unwrap();
+        assert!(log.get_ref().is_empty());
+        assert_eq!(log.buffered(), b"abcdefg");
+        log.write_record(b"hi").unwrap();
+        assert_eq!(log.get_ref(), b"abcdefg");
+        log.write_record(b"a record too long").unwrap();
+        assert_eq!(log.get_ref(), b"abcdefghia record too long");
+        write!(log, "{}", 42).unwrap();
+        assert_eq!(log.into_inner().unwrap(), b"abcdefghia record too long42");
+
+        let mut sink = Vec::new();
+        crate::BumpLogBuffer::new(&mut sink, 8)
+            .write_record(b"dropped")
+            .unwrap();
+        assert_eq!(sink, b"dropped");
+    }
+
+    #[test]
+    fn test_channel() {
+        let (sender, receiver) = crate::channel::channel(64);
+        for round in 0..10 {
+            for i in 0..5 {
+                sender.try_send(format!("{round}-{i}")).unwrap();
+            }
+            assert_eq!(receiver.len(), 5);
+            for i in 0..5 {
+                assert_eq!(receiver.try_recv(), Some(format!("{round}-{i}")));
+            }
+            assert_eq!(receiver.try_recv(), None);
+        }
+        sender.try_send("pending".to_string()).unwrap();
+        drop(sender);
+        assert!(receiver.is_disconnected());
+        assert_eq!(receiver.try_recv().as_deref(), Some("pending"));
+
+        let (sender, receiver) = crate::channel::channel::<u64>(64);
+        drop(receiver);
+        assert_eq!(sender.try_send(1), Err(1));
+    }
+
+    #[cfg(feature = "petgraph")]
+    #[test]
+    fn test_petgraph() {
+        use crate::graph::{try_alloc_weights, CsrBuilder};
+        use petgraph::{algo::toposort, graph::DiGraph};
+
+        let paving = Paving::new(1024, 8);
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a".to_string());
+        let b = graph.add_node("b".to_string());
+        graph.add_edge(b, a, 1_u64);
+        let graph = try_alloc_weights(&paving, graph).unwrap();
+        assert_eq!(*graph[a], "a");
+        assert_eq!(*graph[graph.find_edge(b, a).unwrap()], 1);
+        assert!(same_bump(&graph[a], &graph[b]));
+
+        let mut builder = CsrBuilder::new(3);
+        builder.add_edge(2, 0).add_edge(0, 1);
+        let csr = builder.try_build(&paving).unwrap().to_petgraph();
+        let order: Vec<_> = toposort(&csr, None)
+            .unwrap()
+            .iter()
+            .map(|n| n.index())
+            .collect();
+        assert_eq!(order, [2, 0, 1]);
+    }
+
+    #[test]
+    fn test_assert_no_new_chunks() {
+        let paving = Paving::new(64, 8);
+        let member = paving.assert_no_new_chunks(|| paving.try_alloc(1_u64).unwrap());
+        assert_eq!(*member, 1);
+        assert_eq!(paving.chunks_created(), 1);
+        let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            paving.assert_no_new_chunks(|| {
+                let _members: Vec<_> = (0..10_u64).map(|i| paving.try_alloc(i)).collect();
+            })
+        }));
+        assert!(res.is_err());
+        assert!(paving.chunks_created() > 1);
+    }
+
+    #[test]
+    fn test_memo_cache() {
+        let cache = crate::MemoCache::new(256, 8, 2);
+        let calls = Cell::new(0);
+        let square = |n: &u64| {
+            calls.set(calls.get() + 1);
+            n * n
+        };
+        assert_eq!(*cache.try_get_or_insert_with(3, square).unwrap(), 9);
+        assert_eq!(*cache.try_get_or_insert_with(3, square).unwrap(), 9);
+        assert_eq!(calls.get(), 1);
+        let four = cache.try_get_or_insert_with(4, square).unwrap();
+        assert_eq!(cache.get(&3).as_deref(), Some(&9));
+        cache.try_get_or_insert_with(5, square).unwrap();
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&4).is_none());
+        assert_eq!(*four, 16);
+        assert!(cache.get(&3).is_some());
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn test_weak_cache() {
+        let cache = crate::WeakCache::new(256, 8);
+        let calls = Cell::new(0);
+        let name = |n: &u64| {
+            calls.set(calls.get() + 1);
+            n.to_string()
+        };
+        let three = cache.try_get_or_insert_with(3, name).unwrap();
+        let other_three = cache.try_get_or_insert_with(3, name).unwrap();
+        assert!(std::ptr::eq(&*three, &*other_three));
+        assert_eq!(calls.get(), 1);
+        drop(cache.try_get_or_insert_with(4, name).unwrap());
+        // The value of 4 was dropped, and its entry is pruned when looked up
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&4).is_none());
+        assert_eq!(cache.len(), 1);
+        // Dead entries are pruned once the map has grown enough
+        for i in 5..9 {
+            drop(cache.try_get_or_insert_with(i, name).unwrap());
+        }
+        assert!(cache.len() < 5);
+        cache.prune();
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get(&3).as_deref().map(String::as_str), Some("3"));
+        drop((three, other_three));
+        assert!(cache.get(&3).is_none());
+        assert!(cache.is_empty());
+        assert_eq!(*cache.try_get_or_insert_with(3, name).unwrap(), "3");
+        assert_eq!(calls.get(), 7);
+    }
+
+    #[test]
+    fn test_take() {
+        let bump = Bump::new(16, 8);
+        let mut first = bump.try_alloc(1_u64).unwrap();
+        let second = bump.try_alloc(2_u64).unwrap();
+        assert_eq!(first.replace(10), 1);
+        assert_eq!(first.take(), 10);
+        // Only the last allocation can be reused
+        assert!(bump.try_alloc(3_u64).is_err());
+        assert_eq!(second.take(), 2);
+        assert_eq!(*bump.try_alloc(3_u64).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_weak_member() {
+        struct Node {
+            parent: Option<crate::WeakBumpMember<Node>>,
+            children: RefCell<Vec<RcBumpMember<Node>>>,
+            dropped: Rc<Cell<usize>>,
+        }
+        impl Drop for Node {
+            fn drop(&mut self) {
+                self.dropped.set(self.dropped.get() + 1);
+            }
+        }
+
+        let dropped = Rc::new(Cell::new(0));
+        let paving = Paving::new(1024, 8);
+        let root = paving
+            .try_alloc_rc(Node {
+                parent: None,
+                children: RefCell::new(Vec::new()),
+                dropped: dropped.clone(),
+            })
+            .ok()
+            .unwrap();
+        let child = paving
+            .try_alloc_rc(Node {
+                parent: Some(root.downgrade()),
+                children: RefCell::new(Vec::new()),
+                dropped: dropped.clone(),
+            })
+            .ok()
+            .unwrap();
+        root.children.borrow_mut().push(child.clone());
+        let weak_child = child.downgrade();
+        drop(child);
+        let parent = weak_child.upgrade().unwrap().parent.clone().unwrap();
+        assert!(parent.upgrade().is_some());
+        drop(root);
+        assert_eq!(dropped.get(), 2);
+        assert!(parent.upgrade().is_none());
+        assert!(weak_child.clone().upgrade().is_none());
+
+        let number = paving.try_alloc_rc(1_u64).unwrap();
+        let weak = number.downgrade();
+        drop(number);
+        assert_eq!(weak.upgrade().as_deref(), Some(&1));
+    }
+
+    #[test]
+    fn test_arc_member() {
+        let bump = Bump::new(256, 8);
+        let nodes: Vec<_> = (0..4_u64)
+            .map(|i| bump.try_alloc_arc(vec![i; 3]).unwrap())
+            .collect();
+        drop(bump);
+        let sums: Vec<u64> = std::thread::scope(|scope| {
+            let workers: Vec<_> = nodes
+                .iter()
+                .map(|node| {
+                    let node = node.clone();
+                    scope.spawn(move || node.iter().sum())
+                })
+                .collect();
+            workers.into_iter().map(|w| w.join().unwrap()).collect()
+        });
+        assert_eq!(sums, [0, 3, 6, 9]);
+        std::thread::spawn(move || drop(nodes)).join().unwrap();
+
+        let paving = Paving::new(256, 8);
+        let member = paving.try_alloc(1_u64).unwrap();
+        assert_eq!(member.arena().try_alloc_arc(2_u64).err(), Some(2));
+    }
+
+    #[test]
+    fn test_sync_bump() {
+        let bump = crate::SyncBump::new(64 * 16, 8);
+        let members: Vec<Vec<_>> = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..4_u64)
+                .map(|thread| {
+                    let bump = &bump;
+                    scope.spawn(move || {
+                        (0..16)
+                            .map_while(|i| bump.try_alloc_arc(thread * 100 + i).ok())
+                            .collect()
+                    })
+                })
+                .collect();
+            workers.into_iter().map(|w| w.join().unwrap()).collect()
+        });
+        drop(bump);
+        let mut values: Vec<u64> = members.iter().flatten().map(|m| **m).collect();
+        assert_eq!(values.len(), 64);
+        values.sort();
+        values.dedup();
+        assert_eq!(values.len(), 64);
+        let mut addresses: Vec<_> = members
+            .iter()
+            .flatten()
+            .map(|m| &**m as *const u64)
+            .collect();
+        addresses.sort();
+        addresses.dedup();
+        assert_eq!(addresses.len(), 64);
+    }
+
+    #[test]
+    fn test_sync_paving() {
+        let paving = Arc::new(crate::SyncPaving::new(64, 8));
+        let workers: Vec<_> = (0..4_u64)
+            .map(|thread| {
+                let paving = paving.clone();
+                std::thread::spawn(move || {
+                    (0..20)
+                        .map(|i| paving.try_alloc_arc(thread * 100 + i).unwrap())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        let members: Vec<_> = workers.into_iter().map(|w| w.join().unwrap()).collect();
+        drop(paving);
+        for (thread, members) in members.iter().enumerate() {
+            for (i, member) in members.iter().enumerate() {
+                assert_eq!(**member, thread as u64 * 100 + i as u64);
+            }
+        }
+        assert!(crate::SyncPaving::new(64, 8)
+            .try_alloc_arc([0_u8; 64])
+            .is_err());
+    }
+
+    #[test]
+    fn test_sharded_paving() {
+        let paving = crate::ShardedPaving::new(64, 8);
+        let mut members: Vec<_> = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..4_u64)
+                .map(|thread| {
+                    let paving = &paving;
+                    scope.spawn(move || {
+                        let mut shard = paving.shard();
+                        (0..10)
+                            .map(|i| shard.try_alloc_arc(thread * 100 + i).unwrap())
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|w| w.join().unwrap())
+                .collect()
+        });
+        assert_eq!(members.len(), 40);
+        members.sort_by_key(|member| **member);
+        assert_eq!(*members[10], 100);
+        assert!(paving.chunks_created() >= 4);
+    }
+
+    #[cfg(feature = "allocator-api2")]
+    #[test]
+    fn test_allocator() {
+        use allocator_api2::{boxed::Box, vec::Vec};
+
+        let bump = Bump::new(1024, 8);
+        let mut numbers = Vec::new_in(bump.clone());
+        numbers.extend(0..8_u64);
+        let start = numbers.as_ptr();
+        numbers.extend(8..16);
+        // The vector was the last allocation, and grew in place
+        assert_eq!(numbers.as_ptr(), start);
+        let boxed = Box::new_in(String::from("boxed"), bump.clone());
+        drop(bump);
+        numbers.push(16);
+        assert_eq!(numbers.iter().sum::<u64>(), 136);
+        assert_eq!(*boxed, "boxed");
+    }
+
+    #[cfg(feature = "allocator-api2")]
+    #[test]
+    fn test_paving_allocator() {
+        use allocator_api2::vec::Vec;
+
+        let paving = Paving::new(256, 8);
+        let mut vectors: Vec<Vec<u64, &Paving>> = Vec::new();
+        for i in 0..20 {
+            let mut numbers = Vec::new_in(&paving);
+            numbers.extend(i..i + 4);
+            vectors.push(numbers);
+        }
+        vectors[3].extend(100..110);
+        assert!(paving.chunks_created() > 1);
+        let sums: std::vec::Vec<u64> = vectors.iter().map(|v| v.iter().sum()).collect();
+        assert_eq!(sums[0], 6);
+        assert_eq!(sums[3], 18 + 1045);
+        drop(vectors);
+        assert_eq!(paving.snapshot().chunks, 1);
+    }
+
+    #[test]
+    fn test_bump_vec() {
+        use crate::collections::BumpVec;
+
+        let bump = Bump::new(256, 8);
+        let mut vec = BumpVec::new_in_bump(&bump);
+        for i in 0..4_u64 {
+            vec.try_push(i).unwrap();
+        }
+        let data = vec.as_ptr();
+        // The storage is the last allocation of the bump, so it grows in place
+        vec.try_push(4).unwrap();
+        assert_eq!(vec.as_ptr(), data);
+        assert_eq!(vec.pop(), Some(4));
+        let frozen = vec.try_freeze().ok().unwrap();
+        assert_eq!(*frozen, [0, 1, 2, 3]);
+        // The unused capacity was given back
+        assert_eq!(*bump.try_alloc_rc(4_u64).ok().unwrap(), 4);
+        assert!(same_bump(&frozen, &bump.try_alloc(0_u8).unwrap()));
+
+        let dropped = Rc::new(Cell::new(0));
+        struct Counted(Rc<Cell<usize>>);
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+        let paving = Paving::new(1024, 8);
+        let mut vec = BumpVec::new_in(&paving);
+        for _ in 0..10 {
+            vec.push(Counted(dropped.clone()));
+            // Another allocation prevents growing in place
+            paving.try_alloc(0_u8).unwrap();
+        }
+        vec.truncate(8);
+        assert_eq!(dropped.get(), 2);
+        let frozen = vec.try_freeze().ok().unwrap();
+        assert_eq!(frozen.len(), 8);
+        let weak = frozen.downgrade();
+        let clone = frozen.clone();
+        drop(frozen);
+        assert_eq!(dropped.get(), 2);
+        drop(clone);
+        assert_eq!(dropped.get(), 10);
+        assert!(weak.upgrade().is_none());
+
+        let empty = BumpVec::<String>::new_in(&paving)
+            .try_freeze()
+            .ok()
+            .unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_bump_string() {
+        use crate::collections::BumpString;
+        use std::fmt::Write;
+
+        let paving = Paving::new(256, 8);
+        let mut string = BumpString::from_str_in("key", &paving);
+        string.push_str(" = ");
+        write!(string, "{}", 42).unwrap();
+        string.try_push('!').unwrap();
+        assert_eq!(&*string, "key = 42!");
+        let member = string.into_member();
+        assert_eq!(&*member, "key = 42!");
+        assert!(same_bump(&member, &paving.try_alloc(0_u8).unwrap()));
+
+        let formatted = crate::bump_format!(paving, "{}-{:03}", "id", 7).unwrap();
+        assert_eq!(&*formatted, "id-007");
+        assert!(same_bump(&formatted, &member));
+    }
+
+    #[test]
+    fn test_alloc_str() {
+        let bump = Bump::new(16, 1);
+        let token = bump.try_alloc_str("token").unwrap();
+        assert_eq!(&*token, "token");
+        assert!(bump.try_alloc_str("does not fit").is_none());
+
+        let paving = Paving::new(16, 1);
+        let tokens: Vec<_> = ["let", "x", "=", "1;"]
+            .iter()
+            .map(|s| paving.try_alloc_str(s).unwrap())
+            .collect();
+        assert_eq!(tokens.iter().map(|t| &**t).collect::<String>(), "letx=1;");
+        let long = paving.try_alloc_str("much too long for a bump").unwrap();
+        assert_eq!(&*long, "much too long for a bump");
+    }
+
+    #[test]
+    fn test_alloc_slice_copy() {
+        let paving = Paving::new(64, 8);
+        let mut samples = paving.try_alloc_slice_copy(&[1.0_f64, 2.0, 3.0]).unwrap();
+        samples[1] = 4.0;
+        assert_eq!(*samples, [1.0, 4.0, 3.0]);
+        let empty = paving.try_alloc_slice_copy::<u64>(&[]).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(paving.try_alloc_slice_copy(&[0_u64; 9]).unwrap().len(), 9);
+    }
+
+    #[test]
+    fn test_alloc_slice_clone() {
+        struct Fragile(Rc<Cell<usize>>);
+        impl Clone for Fragile {
+            fn clone(&self) -> Self {
+                if self.0.get() == 1 {
+                    panic!("Cannot clone twice");
+                }
+                self.0.set(self.0.get() + 1);
+                Fragile(self.0.clone())
+            }
+        }
+        impl Drop for Fragile {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 10);
+            }
+        }
+
+        let paving = Paving::new(1024, 8);
+        let names = paving
+            .try_alloc_slice_clone(&[String::from("a"), String::from("b")])
+            .unwrap();
+        assert_eq!(*names, ["a", "b"]);
+
+        let bump = Bump::new(64, 8);
+        let clones = Rc::new(Cell::new(0));
+        let src = [Fragile(clones.clone()), Fragile(clones.clone())];
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            bump.try_alloc_slice_clone(&src).map(drop)
+        }));
+        assert!(result.is_err());
+        // The first clone was dropped
+        assert_eq!(clones.get(), 11);
+        // The room of the slice was given back
+        assert!(bump.try_alloc([0_u64; 8]).is_ok());
+    }
+
+    #[test]
+    fn test_alloc_slice_fill() {
+        let bump = Bump::new(256, 8);
+        let squares = bump.try_alloc_slice_fill_with(4, |i| i * i).unwrap();
+        assert_eq!(*squares, [0, 1, 4, 9]);
+        let zeros = bump.try_alloc_slice_fill_default::<u32>(3).unwrap();
+        assert_eq!(*zeros, [0; 3]);
+        let paving = Paving::new(1024, 8);
+        let ones = paving.try_alloc_slice_fill_copy(100, 1_u8).unwrap();
+        assert_eq!(ones.iter().map(|&x| usize::from(x)).sum::<usize>(), 100);
+        let names = paving
+            .try_alloc_slice_fill_with(2, |i| format!("item{i}"))
+            .unwrap();
+        assert_eq!(*names, ["item0", "item1"]);
+        assert_eq!(
+            paving.try_alloc_slice_fill_copy(1025, 0_u8).unwrap().len(),
+            1025
+        );
+    }
+
+    #[test]
+    fn test_alloc_from_iter() {
+        // Announces more values than it yields
+        struct Liar(std::ops::Range<u64>);
+        impl Iterator for Liar {
+            type Item = u64;
+            fn next(&mut self) -> Option<u64> {
+                self.0.next()
+            }
+        }
+        impl ExactSizeIterator for Liar {
+            fn len(&self) -> usize {
+                self.0.end as usize * 2
+            }
+        }
+
+        let bump = Bump::new(64, 8);
+        let values = bump
+            .try_alloc_from_iter((0..4_u32).map(|i| i * 10))
+            .unwrap();
+        assert_eq!(*values, [0, 10, 20, 30]);
+        let short = bump.try_alloc_from_iter(Liar(0..2)).unwrap();
+        assert_eq!(*short, [0, 1]);
+        // The room of the missing values was given back
+        assert!(bump.try_alloc([0_u64; 4]).is_ok());
+
+        let paving = Paving::new(256, 8);
+        let strings = paving
+            .try_alloc_from_iter(vec![String::from("x"), String::from("y")])
+            .unwrap();
+        assert_eq!(*strings, ["x", "y"]);
+    }
+
+    #[test]
+    fn test_unsized_rc_member() {
+        use std::any::Any;
+
+        let paving = Paving::new(1024, 8);
+        let dropped = Rc::new(Cell::new(false));
+        struct Flag(Rc<Cell<bool>>);
+        impl Drop for Flag {
+            fn drop(&mut self) {
+                self.0.set(true);
+            }
+        }
+
+        // Values with and without drop glue can share the same erased type
+        let values: Vec<RcBumpMember<dyn Any>> = vec![
+            paving.try_alloc_rc(1_u64).ok().unwrap().into_any(),
+            paving
+                .try_alloc_rc(Flag(dropped.clone()))
+                .ok()
+                .unwrap()
+                .into_any(),
+        ];
+        let shared = values.clone();
+        drop(values);
+        assert!(!dropped.get());
+        let mut shared = shared.into_iter();
+        let number = shared.next().unwrap().downcast::<u64>().ok().unwrap();
+        assert_eq!(*number, 1);
+        let flag = shared.next().unwrap();
+        let flag = flag.downcast::<u64>().err().unwrap();
+        let weak = flag.downgrade();
+        drop(flag);
+        assert!(dropped.get());
+        assert!(weak.upgrade().is_none());
+
+        let slice = paving.try_alloc_rc([1, 2, 3]).ok().unwrap().into_slice();
+        assert_eq!(slice.iter().sum::<i32>(), 6);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_coerce_unsized() {
+        use std::fmt::Display;
+
+        let paving = Paving::new(1024, 8);
+        let values: Vec<RcBumpMember<dyn Display>> = vec![
+            paving.try_alloc_rc(1_u8).ok().unwrap(),
+            paving.try_alloc_rc(String::from("two")).ok().unwrap(),
+        ];
+        let text: Vec<_> = values.iter().map(|v| v.to_string()).collect();
+        assert_eq!(text, ["1", "two"]);
+        let weak: crate::WeakBumpMember<dyn Display> = values[1].downgrade();
+        drop(values);
+        assert!(weak.upgrade().is_none());
+        let slice: crate::BumpMember<[u32]> = paving.try_alloc([1, 2]).ok().unwrap();
+        assert_eq!(slice.len(), 2);
+    }
+
+    #[test]
+    fn test_alloc_dyn() {
+        trait Plugin {
+            fn run(&self) -> usize;
+        }
+        struct Counter(Rc<Cell<usize>>);
+        impl Plugin for Counter {
+            fn run(&self) -> usize {
+                self.0.set(self.0.get() + 1);
+                self.0.get()
+            }
+        }
+        impl Plugin for u8 {
+            fn run(&self) -> usize {
+                usize::from(*self)
+            }
+        }
+
+        let paving = Paving::new(1024, 8);
+        let bump = Bump::new(64, 8);
+        let runs = Rc::new(Cell::new(0));
+        let plugins: Vec<RcBumpMember<dyn Plugin>> = vec![
+            crate::alloc_dyn!(paving, dyn Plugin, Counter(runs.clone()))
+                .ok()
+                .unwrap(),
+            crate::alloc_dyn!(bump, dyn Plugin, 7_u8).ok().unwrap(),
+        ];
+        assert_eq!(plugins.iter().map(|p| p.run()).sum::<usize>(), 8);
+        assert_eq!(runs.get(), 1);
+        drop(plugins);
+        assert_eq!(Rc::strong_count(&runs), 1);
+    }
+
+    #[test]
+    fn test_strong_count() {
+        let paving = Paving::new(1024, 8);
+        let text = paving.try_alloc_rc(String::from("shared")).ok().unwrap();
+        assert_eq!(text.strong_count(), 1);
+        let clone = text.clone();
+        let _weak = text.downgrade();
+        assert_eq!(text.strong_count(), 2);
+        drop(clone);
+        assert_eq!(text.strong_count(), 1);
+
+        let bump = Bump::new(64, 8);
+        let number = bump.try_alloc_rc(1_u64).ok().unwrap();
+        let other = bump.try_alloc_rc(2_u64).ok().unwrap();
+        // Counts the bump handle and the other member too
+        assert_eq!(number.strong_count(), 3);
+        assert_eq!(other.clone().strong_count(), 4);
+    }
+
+    #[test]
+    fn test_rc_get_mut() {
+        let paving = Paving::new(1024, 8);
+        let mut list = paving.try_alloc_rc(vec![1, 2]).ok().unwrap();
+        list.get_mut().unwrap().push(3);
+        let clone = list.clone();
+        assert!(list.get_mut().is_none());
+        drop(clone);
+        let weak = list.downgrade();
+        assert!(list.get_mut().is_none());
+        drop(weak);
+        assert_eq!(*list.get_mut().unwrap(), [1, 2, 3]);
+
+        let bump = Bump::new(64, 8);
+        let mut number = bump.try_alloc_rc(1_u64).ok().unwrap();
+        // The bump handle keeps the bump alive
+        assert!(number.get_mut().is_none());
+        drop(bump);
+        *number.get_mut().unwrap() += 1;
+        assert_eq!(*number, 2);
+    }
+
+    #[test]
+    fn test_into_rc() {
+        let bump = Bump::new(128, 8);
+        let mut number = bump.try_alloc(1_u64).unwrap();
+        *number += 1;
+        let address = &*number as *const u64;
+        let shared = number.into_rc();
+        // Shared in place
+        assert_eq!(&*shared as *const u64, address);
+        assert_eq!(*shared.clone(), 2);
+
+        let mut text = bump.try_alloc(String::from("draft")).unwrap();
+        text.push_str(" 2");
+        let shared = text.try_into_rc().ok().unwrap();
+        let clone = shared.clone();
+        assert_eq!(shared.strong_count(), 2);
+        assert_eq!(*clone, "draft 2");
+
+        let full = Bump::new(24, 8);
+        let text = full.try_alloc(String::new()).unwrap();
+        assert!(text.try_into_rc().is_err());
+    }
+
+    #[test]
+    fn test_alloc_rc_cyclic() {
+        struct Node {
+            this: crate::WeakBumpMember<Node>,
+            value: u32,
+        }
+
+        let paving = Paving::new(1024, 8);
+        let node = paving
+            .try_alloc_rc_cyclic(|this| {
+                assert!(this.upgrade().is_none());
+                Node {
+                    this: this.clone(),
+                    value: 3,
+                }
+            })
+            .ok()
+            .unwrap();
+        let again = node.this.upgrade().unwrap();
+        assert_eq!(again.value, 3);
+        assert_eq!(node.strong_count(), 2);
+
+        // Values without drop glue cannot be reached before being built either
+        let bump = Bump::new(64, 8);
+        let number = bump
+            .try_alloc_rc_cyclic(|this: &crate::WeakBumpMember<u64>| {
+                assert!(this.upgrade().is_none());
+                7
+            })
+            .ok()
+            .unwrap();
+        assert_eq!(*number, 7);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            bump.try_alloc_rc_cyclic(|_: &crate::WeakBumpMember<u64>| panic!("Cannot build"))
+                .map(drop)
+        }));
+        assert!(result.is_err());
+        drop(bump);
+        assert_eq!(number.strong_count(), 1);
+    }
+
+    #[test]
+    fn test_member_traits() {
+        use std::collections::{BTreeSet, HashMap};
+
+        let paving = Paving::new(1024, 8);
+        let mut counts = HashMap::new();
+        for word in ["b", "a", "b"] {
+            let key = paving.try_alloc_rc(String::from(word)).ok().unwrap();
+            *counts.entry(key).or_insert(0) += 1;
+        }
+        let b = paving.try_alloc_rc(String::from("b")).ok().unwrap();
+        assert_eq!(counts[&b], 2);
+
+        let sorted: BTreeSet<_> = [3, 1, 2]
+            .into_iter()
+            .map(|n| paving.try_alloc(n).unwrap())
+            .collect();
+        assert_eq!(format!("{sorted:?}"), "{1, 2, 3}");
+        assert_eq!(format!("{b}"), "b");
+        assert_eq!(format!("{:?}", b.downgrade()), "(Weak)");
+    }
+
+    #[test]
+    fn test_member_comparisons() {
+        let paving = Paving::new(1024, 8);
+        let member = paving.try_alloc_rc(String::from("b")).ok().unwrap();
+        let plain = String::from("b");
+        assert!(member == plain);
+        let borrowed = &plain;
+        assert!(member == borrowed);
+        assert!(member == Rc::new(plain.clone()));
+        assert!(member < String::from("c"));
+        let a = String::from("a");
+        let borrowed = &a;
+        assert!(member > borrowed);
+        let number = paving.try_alloc(2_u32).unwrap();
+        assert!(number == 2);
+        assert!(number < Rc::new(3));
+        let slice = paving.try_alloc_slice_copy(&[1, 2]).unwrap();
+        assert!(slice == &[1, 2][..]);
+    }
+
+    #[test]
+    fn test_member_borrow() {
+        use std::{borrow::BorrowMut, collections::HashSet};
+
+        fn total(values: impl AsRef<[u32]>) -> u32 {
+            values.as_ref().iter().sum()
+        }
+
+        let paving = Paving::new(1024, 8);
+        let names: HashSet<RcBumpMember<str>> = ["ada", "grace"]
+            .into_iter()
+            .map(|name| crate::bump_format!(paving, "{name}").unwrap())
+            .collect();
+        assert!(names.contains("ada"));
+        assert!(!names.contains("alan"));
+
+        let mut values = paving.try_alloc_slice_copy(&[1, 2, 3]).unwrap();
+        let slice: &mut [u32] = values.borrow_mut();
+        slice[0] = 4;
+        assert_eq!(total(values), 9);
+    }
+
+    #[test]
+    fn test_member_pointer() {
+        let paving = Paving::new(1024, 8);
+        let first = paving.try_alloc_rc(1_u64).ok().unwrap();
+        let second = paving.try_alloc_rc(1_u64).ok().unwrap();
+        assert_eq!(first, second);
+        assert_ne!(RcBumpMember::as_ptr(&first), RcBumpMember::as_ptr(&second));
+        assert_eq!(
+            RcBumpMember::as_ptr(&first),
+            RcBumpMember::as_ptr(&first.clone())
+        );
+        assert_eq!(format!("{first:p}"), format!("{:p}", &*first));
+        let slice = paving.try_alloc_slice_copy(&[1, 2]).unwrap();
+        assert_eq!(
+            crate::BumpMember::as_ptr(&slice).cast::<i32>(),
+            slice.as_ptr()
+        );
+    }
+
+    #[cfg(feature = "raw")]
+    #[test]
+    fn test_into_raw() {
+        use crate::BumpMember;
+
+        let paving = Paving::new(64, 8);
+        let raw: Vec<*mut String> = (0..4)
+            .map(|i| BumpMember::into_raw(paving.try_alloc(i.to_string()).unwrap()))
+            .collect();
+        let shared = paving.try_alloc_rc(String::from("shared")).ok().unwrap();
+        let raw_shared = RcBumpMember::into_raw(shared.clone());
+        let unit = RcBumpMember::into_raw(paving.try_alloc_rc(()).ok().unwrap());
+        drop(paving);
+        // Safety: the pointers come from into_raw
+        let members: Vec<_> = raw
+            .into_iter()
+            .map(|ptr| unsafe { BumpMember::from_raw(ptr) })
+            .collect();
+        assert_eq!(
+            members.iter().map(|m| m.as_str()).collect::<String>(),
+            "0123"
+        );
+        // Safety: the pointers come from into_raw
+        let again = unsafe { RcBumpMember::from_raw(raw_shared) };
+        assert_eq!(shared.strong_count(), 2);
+        assert!(same_bump(&again, &shared));
+        // Safety: the pointer comes from into_raw
+        drop(unsafe { RcBumpMember::from_raw(unit) });
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "leaks on purpose")]
+    fn test_leak() {
+        struct Config {
+            name: String,
+        }
+
+        let config: &'static mut Config = {
+            let paving = Paving::new(1024, 8);
+            let member = paving
+                .try_alloc(Config {
+                    name: String::from("prod"),
+                })
+                .ok()
+                .unwrap();
+            crate::BumpMember::leak(member)
+        };
+        config.name.push_str("uction");
+        assert_eq!(config.name, "production");
+    }
+
+    #[test]
+    fn test_clone_member() {
+        let paving = Paving::new(64, 8);
+        let original = paving.try_alloc(String::from("text")).unwrap();
+        let clone = original.clone();
+        assert_eq!(clone, original);
+        assert!(same_bump(&clone, &original));
+        let moved = original.try_clone_in(&paving).unwrap();
+        assert_eq!(*moved, "text");
+
+        let full = Bump::new(8, 8);
+        let number = full.try_alloc(1_u64).unwrap();
+        let clone = number.clone();
+        assert!(!same_bump(&clone, &number));
+        assert_eq!(clone, 1);
+    }
+
+    #[test]
+    fn test_map_member() {
+        struct Node {
+            name: String,
+            edges: Vec<u32>,
+        }
+        let paving = Paving::new(256, 8);
+        let node = paving
+            .try_alloc(Node {
+                name: String::from("a"),
+                edges: vec![1, 2],
+            })
+            .ok()
+            .unwrap();
+        let mut edges = BumpMember::map(node, |node| &mut node.edges);
+        edges.push(3);
+        let mut first = MappedBumpMember::map(edges, |edges| &mut edges[0]);
+        *first += 10;
+        assert_eq!(*first, 11);
+
+        let node = paving
+            .try_alloc_rc(Node {
+                name: String::from("b"),
+                edges: Vec::new(),
+            })
+            .ok()
+            .unwrap();
+        let name = RcBumpMember::map(node.clone(), |node| node.name.as_str());
+        assert_eq!(node.strong_count(), 2);
+        drop(node);
+        let other = name.clone();
+        assert!(same_bump(&name, &other));
+        assert_eq!(&*other, "b");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_members() {
+        use serde::de::DeserializeSeed;
+
+        #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+        struct Config {
+            name: String,
+            retries: u32,
+        }
+        let paving = Paving::new(128, 8);
+        let config = paving
+            .try_alloc(Config {
+                name: String::from("main"),
+                retries: 3,
+            })
+            .ok()
+            .unwrap();
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"name":"main","retries":3}"#);
+
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let shared = paving
+            .seed::<RcBumpMember<Config>>()
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(*shared, *config);
+        assert_eq!(serde_json::to_string(&shared).unwrap(), json);
+
+        let small = Paving::new(8, 8);
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let seed = small.seed::<BumpMember<Config>>();
+        assert_eq!(*seed.deserialize(&mut deserializer).unwrap(), *config);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_in_paving() {
+        use serde::de::DeserializeSeed;
+
+        use crate::{BumpBytes, BumpStr};
+
+        let paving = Paving::new(1024, 8);
+        let json = r#"[["a", "bc"], [], ["def"]]"#;
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        let nested = paving
+            .seed::<RcBumpMember<[RcBumpMember<[BumpMember<str>]>]>>()
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(nested.len(), 3);
+        assert_eq!(*nested[0], ["a", "bc"]);
+        assert!(nested[1].is_empty());
+        assert!(same_bump(&nested[2][0], &nested));
+        assert_eq!(
+            serde_json::to_string(&nested).unwrap(),
+            r#"[["a","bc"],[],["def"]]"#
+        );
+
+        let bytes = paving.try_alloc_bump_bytes(&[1, 2, 3]).unwrap();
+        let json = serde_json::to_string(&bytes).unwrap();
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let copy = paving
+            .seed::<BumpBytes>()
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(*copy, [1, 2, 3]);
+
+        let mut deserializer = serde_json::Deserializer::from_str(r#""text""#);
+        let text = paving
+            .seed::<BumpStr>()
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(&*text, "text");
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    #[cfg_attr(miri, ignore = "rkyv validation breaks stacked borrows")]
+    fn test_rkyv_archive() {
+        #[derive(rkyv::Archive, rkyv::Serialize)]
+        #[rkyv(serialize_bounds(
+            __S: rkyv::ser::Writer + rkyv::ser::Allocator + rkyv::ser::Sharing,
+            __S::Error: rkyv::rancor::Source,
+        ))]
+        #[rkyv(bytecheck(bounds(
+            __C: rkyv::validation::ArchiveContext + rkyv::validation::SharedContext,
+            __C::Error: rkyv::rancor::Source,
+        )))]
+        struct Node {
+            name: BumpMember<str>,
+            #[rkyv(omit_bounds)]
+            edges: Vec<RcBumpMember<Node>>,
+        }
+        let paving = Paving::new(1024, 8);
+        let leaf = paving
+            .try_alloc_rc(Node {
+                name: paving.try_alloc_str("leaf").unwrap(),
+                edges: Vec::new(),
+            })
+            .ok()
+            .unwrap();
+        let root = Node {
+            name: paving.try_alloc_str("root").unwrap(),
+            edges: vec![leaf.clone(), leaf],
+        };
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&root).unwrap();
+        let archive = paving.try_alloc_archive(&bytes).unwrap();
+        let archived = rkyv::access::<ArchivedNode, rkyv::rancor::Error>(&archive).unwrap();
+        assert_eq!(&*archived.name, "root");
+        assert_eq!(archived.edges.len(), 2);
+        assert_eq!(&*archived.edges[0].name, "leaf");
+        assert!(std::ptr::eq(&*archived.edges[0], &*archived.edges[1]));
+    }
+
+    #[test]
+    fn test_pod_paving_dump() {
+        use crate::{PodKey, PodPaving};
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[repr(C)]
+        struct Edge {
+            from: PodKey,
+            to: PodKey,
+            weight: f64,
+        }
+        // Safety: Edge is made of Pod fields without padding
+        unsafe impl crate::Pod for Edge {}
+
+        let mut nodes = PodPaving::<[f32; 2]>::new(64, 8);
+        let mut edges = PodPaving::<Edge>::new(64, 8);
+        let keys: Vec<_> = (0..10)
+            .map(|i| nodes.try_alloc([i as f32, -i as f32]).unwrap())
+            .collect();
+        let mut edge_keys = Vec::new();
+        for (i, pair) in keys.windows(2).enumerate() {
+            let edge = Edge {
+                from: pair[0],
+                to: pair[1],
+                weight: i as f64,
+            };
+            edge_keys.push(edges.try_alloc(edge).unwrap());
+        }
+        let (mut node_dump, mut edge_dump) = (Vec::new(), Vec::new());
+        nodes.write_to(&mut node_dump).unwrap();
+        edges.write_to(&mut edge_dump).unwrap();
+
+        let nodes = PodPaving::<[f32; 2]>::read_from(node_dump.as_slice()).unwrap();
+        let edges = PodPaving::<Edge>::read_from(edge_dump.as_slice()).unwrap();
+        assert_eq!((nodes.len(), edges.len()), (10, 9));
+        let edge = edges.get(edge_keys[7]).unwrap();
+        assert_eq!(edge.weight, 7.0);
+        assert_eq!(nodes.get(edge.to), Some(&[8.0, -8.0]));
+        // The chunks are reloaded as a whole, keeping values in place
+        let address = |key| nodes.get(key).unwrap() as *const [f32; 2] as usize;
+        assert_eq!(address(keys[1]) - address(keys[0]), 8);
+
+        let err = PodPaving::<u32>::read_from(edge_dump.as_slice())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        let mut swapped = edge_dump.clone();
+        swapped[8..16].reverse();
+        let err = PodPaving::<Edge>::read_from(swapped.as_slice())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        edge_dump.truncate(edge_dump.len() - 1);
+        assert!(PodPaving::<Edge>::read_from(edge_dump.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_pod_paving_portable() {
+        use crate::PodPaving;
+
+        let mut values = PodPaving::<u32>::new(64, 8);
+        let keys: Vec<_> = (1..4).map(|i| values.try_alloc(i).unwrap()).collect();
+        let mut dump = Vec::new();
+        values.write_to(&mut dump).unwrap();
+        // The same dump, as written on a platform with the other byte order
+        let mut swapped = dump.clone();
+        swapped[8..16].reverse();
+        let values_start = swapped.len() - 12;
+        for value in swapped[values_start..].chunks_mut(4) {
+            value.reverse();
+        }
+
+        let err = PodPaving::<u32>::read_from(swapped.as_slice())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        for dump in [dump, swapped] {
+            let values = PodPaving::<u32>::read_portable_from(dump.as_slice()).unwrap();
+            assert_eq!(
+                keys.iter()
+                    .map(|&key| values.get(key).copied())
+                    .collect::<Vec<_>>(),
+                [Some(1), Some(2), Some(3)]
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "relative pointers need tree borrows")]
+    fn test_rel_member() {
+        use crate::{Member, RelMember};
+
+        struct Node {
+            value: u64,
+            next: RelMember<u64>,
+        }
+        let bump = Bump::new(64, 8);
+        let mut node = bump
+            .try_alloc(Node {
+                value: 3,
+                next: RelMember::null(),
+            })
+            .ok()
+            .unwrap();
+        assert!(node.next.is_null());
+        let chunk = node.chunk();
+        let elsewhere = Bump::new(64, 8).try_alloc(4_u64).ok().unwrap();
+        let Node { value, next } = &mut *node;
+        // Targets are only set in the same chunk
+        assert!(!next.try_set(&elsewhere, &chunk));
+        assert!(next.is_null());
+        assert!(next.try_set(value, &chunk));
+        // Safety: the target is in the same node
+        assert_eq!(unsafe { node.next.get() }, Some(&3));
+
+        // Moving the node moves the target along
+        let moved = Bump::new(64, 8).try_alloc(node.take()).ok().unwrap();
+        // Safety: the target is in the same node
+        assert_eq!(unsafe { moved.next.get() }, Some(&3));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "relative pointers need tree borrows")]
+    fn test_rel_member_dump() {
+        use crate::{PodPaving, RelMember};
+
+        #[derive(Clone, Copy, Debug)]
+        #[repr(C)]
+        struct Cursor {
+            values: [u64; 4],
+            current: RelMember<u64>,
+        }
+        // Safety: Cursor is made of Pod fields without padding
+        unsafe impl crate::Pod for Cursor {}
+
+        let mut cursors = PodPaving::<Cursor>::new(64, 8);
+        let key = cursors
+            .try_alloc(Cursor {
+                values: [1, 2, 3, 4],
+                current: RelMember::null(),
+            })
+            .unwrap();
+        let chunk = cursors.chunk(key).unwrap();
+        let Cursor { values, current } = cursors.get_mut(key).unwrap();
+        assert!(current.try_set(&values[2], &chunk));
+        let mut dump = Vec::new();
+        cursors.write_to(&mut dump).unwrap();
+        drop(cursors);
+
+        let cursors = PodPaving::<Cursor>::read_from(dump.as_slice()).unwrap();
+        // Safety: the target was reloaded along with the member
+        assert_eq!(unsafe { cursors.get(key).unwrap().current.get() }, Some(&3));
+    }
+
+    #[test]
+    fn test_alloc_error_kind() {
+        let paving = Paving::new(64, 8);
+        assert_eq!(paving.try_alloc_detailed([1_u8; 72]).unwrap()[71], 1);
+        assert_eq!(*paving.try_alloc_rc_detailed(1_u64).unwrap(), 1);
+
+        #[cfg(feature = "failpoints")]
+        {
+            let paving = Paving::new(64, 8).with_failure_every(1);
+            let err = paving.try_alloc_detailed(1_u8).err().unwrap();
+            assert_eq!(err.kind(), crate::AllocErrorKind::Injected);
+        }
+    }
+
+    #[test]
+    fn test_alloc_error_display() {
+        use std::error::Error;
+
+        use crate::AllocErrorKind;
+
+        assert_eq!(
+            AllocErrorKind::TooLarge.to_string(),
+            "the object is too large for the paving"
+        );
+        let kind = AllocErrorKind::NewBump(NewBumpError::OutOfMemory);
+        assert_eq!(
+            kind.source().unwrap().to_string(),
+            "memory allocation failed"
+        );
+
+        #[cfg(feature = "failpoints")]
+        {
+            fn alloc(paving: &Paving) -> Result<u8, Box<dyn Error>> {
+                let member = paving.try_alloc_detailed(0_u8)?;
+                Ok(*member)
+            }
+            let err = alloc(&Paving::new(64, 8).with_failure_every(1))
+                .err()
+                .unwrap();
+            assert_eq!(
+                err.to_string(),
+                "allocation failed: injected allocation failure"
+            );
+        }
+    }
+
+    #[test]
+    fn test_alloc_with() {
+        let paving = Paving::new(4096, 8);
+        let large = paving.try_alloc_with(|| [7_u64; 256]).ok().unwrap();
+        assert_eq!(large[255], 7);
+        let shared = paving
+            .try_alloc_rc_with(|| String::from("built"))
+            .ok()
+            .unwrap();
+        assert_eq!(*shared, "built");
+
+        let bump = Bump::new(64, 8);
+        let used = bump.snapshot().used;
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            bump.try_alloc_with(|| -> u64 { panic!("building failed") })
+        }));
+        assert!(panicked.is_err());
+        assert_eq!(bump.snapshot().used, used);
+        assert!(bump.try_alloc_with(|| [0_u8; 65]).is_err());
+    }
+
+    #[test]
+    fn test_alloc_try_with() {
+        let bump = Bump::new(64, 8);
+        let used = bump.snapshot().used;
+        let failed = bump
+            .try_alloc_try_with(|| "3x".parse::<u64>())
+            .ok()
+            .unwrap();
+        assert!(failed.is_err());
+        assert_eq!(bump.snapshot().used, used);
+
+        let parsed = bump.try_alloc_try_with(|| "32".parse::<u64>());
+        assert_eq!(*parsed.ok().unwrap().unwrap(), 32);
+
+        let paving = Paving::new(64, 8);
+        let built = paving.try_alloc_try_with(|| Ok::<_, ()>([1_u8; 8]));
+        assert_eq!(*built.ok().unwrap().unwrap(), [1; 8]);
+    }
+
+    #[test]
+    fn test_alloc_uninit() {
+        use std::ptr::addr_of_mut;
+
+        struct Large {
+            id: u64,
+            values: [u64; 128],
+        }
+        let paving = Paving::new(4096, 8);
+        let mut uninit = paving.try_alloc_uninit::<Large>().unwrap();
+        let ptr = uninit.as_mut_ptr();
+        // Safety: ptr points to a Large, only the address of a field is taken
+        let id = unsafe { addr_of_mut!((*ptr).id) };
+        // Safety: same as above
+        let values = unsafe { addr_of_mut!((*ptr).values) };
+        // Safety: the fields are valid for writes
+        unsafe { id.write(3) };
+        // Safety: the fields are valid for writes
+        unsafe { values.write([5; 128]) };
+        // Safety: both fields were initialized
+        let large = unsafe { uninit.assume_init() };
+        assert_eq!(large.id, 3);
+        assert_eq!(large.values[127], 5);
+    }
+
+    #[test]
+    fn test_alloc_slice_uninit() {
+        use std::io::Read;
+
+        let paving = Paving::new(4096, 8);
+        let mut buffer = paving.try_alloc_slice_uninit::<u8>(1024).unwrap();
+        let mut source = std::io::repeat(7).take(1024);
+        for chunk in buffer.chunks_mut(100) {
+            let mut bytes = [0; 100];
+            let read = source.read(&mut bytes[..chunk.len()]).unwrap();
+            for (slot, byte) in chunk.iter_mut().zip(&bytes[..read]) {
+                slot.write(*byte);
+            }
+        }
+        // Safety: every byte was written
+        let buffer = unsafe { buffer.assume_init_slice() };
+        assert_eq!(buffer.len(), 1024);
+        assert!(buffer.iter().all(|&byte| byte == 7));
+        assert!(Bump::new(64, 8).try_alloc_slice_uninit::<u64>(9).is_none());
+    }
+
+    #[test]
+    fn test_alloc_layout() {
+        let paving = Paving::new(256, 8);
+        let layout = std::alloc::Layout::from_size_align(24, 8).unwrap();
+        let room = paving.try_alloc_layout(layout).unwrap();
+        assert_eq!(room.layout(), layout);
+        assert_eq!(room.as_ptr().as_ptr() as usize % 8, 0);
+        let header = room.as_ptr().cast::<u64>();
+        // Safety: the room holds 24 bytes aligned to 8
+        unsafe { header.as_ptr().write(3) };
+        // Safety: the header was just written
+        assert_eq!(unsafe { header.as_ptr().read() }, 3);
+
+        let member = paving.try_alloc(1_u8).unwrap();
+        assert!(same_bump(&room, &member));
+        drop(paving);
+        drop(member);
+        // The room keeps its bump alive
+        assert!(Bump::of_member(&room).contains(room.as_ptr().as_ptr()));
+    }
+
+    #[test]
+    fn test_alloc_unchecked() {
+        let bump = Bump::new(1024, 8);
+        bump.try_alloc(1_u8).unwrap();
+        assert!(bump.try_reserve::<u64>(100));
+        assert!(!bump.try_reserve::<u64>(128));
+        let mut sum = 0;
+        for i in 0..100_u64 {
+            // Safety: room for 100 u64 was reserved
+            let value = unsafe { bump.alloc_unchecked(i) };
+            *value += 1;
+            sum += *value;
+        }
+        assert_eq!(sum, 5050);
+        assert_eq!(bump.snapshot().used, 8 + 800);
+    }
+
+    #[test]
+    fn test_guard() {
+        let paving = Paving::new(64, 8);
+        let dropped = Cell::new(0);
+        struct Counted<'a>(&'a Cell<u32>);
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+        {
+            let guard = paving.guard();
+            let members: Vec<_> = (0..20)
+                .map(|_| guard.try_alloc(Counted(&dropped)).ok().unwrap())
+                .collect();
+            let words = guard.try_alloc_slice_copy(&[1_u64, 2, 3]).unwrap();
+            assert_eq!(*words, [1, 2, 3]);
+            let leaked = ScopedMember::leak(guard.try_alloc(5_u64).ok().unwrap());
+            *leaked += 1;
+            assert_eq!(*leaked, 6);
+            assert!(!same_bump(&members[0], &members[19]));
+            drop(members);
+            assert_eq!(dropped.get(), 20);
+        }
+
+        let bump = Bump::new(64, 8);
+        let guard = bump.guard();
+        drop(bump);
+        let member = guard.try_alloc(7_u32).ok().unwrap();
+        assert_eq!(*member, 7);
+        assert!(guard.try_alloc([0_u8; 64]).is_err());
+    }
+
+    #[test]
+    fn test_realloc_last() {
+        let bump = Bump::new(256, 8);
+        let layout = std::alloc::Layout::from_size_align(16, 8).unwrap();
+        let mut room = bump.try_alloc_layout(layout).unwrap();
+        assert!(room.try_realloc(64));
+        assert_eq!(room.layout().size(), 64);
+        assert!(!room.try_realloc(1024));
+        assert!(room.try_realloc(8));
+        let used = bump.snapshot().used;
+
+        let next = bump.try_alloc(0_u64).unwrap();
+        assert_eq!(bump.snapshot().used, used + 8);
+        assert!(!room.try_realloc(16));
+
+        let start = std::ptr::NonNull::from(&*next).cast::<u8>();
+        // Safety: next is 8 bytes long
+        let end = unsafe { start.add(8) };
+        assert!(bump.try_extend_last(end, 8));
+        assert_eq!(bump.snapshot().used, used + 16);
+        // Safety: next holds 16 bytes now, and no byte past 8 is used
+        assert!(unsafe { bump.try_realloc_last(start, 16, 8) });
+        assert_eq!(bump.snapshot().used, used + 8);
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        let bump = Bump::new(256, 8);
+        let kept = bump.try_alloc(1_u64).unwrap();
+        let checkpoint = bump.checkpoint();
+        let used = bump.snapshot().used;
+        let speculative = bump.try_alloc_str("a failed branch").unwrap();
+        drop(speculative);
+        // Safety: nothing allocated after the checkpoint is alive
+        assert!(unsafe { bump.reset_to(checkpoint) });
+        assert_eq!(bump.snapshot().used, used);
+        assert_eq!(*kept, 1);
+
+        let other = Bump::new(256, 8);
+        // Safety: the checkpoint is rejected
+        assert!(!unsafe { other.reset_to(checkpoint) });
+        let earlier = bump.checkpoint();
+        let _member = bump.try_alloc(2_u64).unwrap();
+        // Safety: nothing is rewound
+        assert!(unsafe { bump.reset_to(bump.checkpoint()) });
+        assert_ne!(bump.checkpoint(), earlier);
+    }
+
+    #[test]
+    fn test_zero_sized() {
+        #[derive(Debug, PartialEq)]
+        struct Unit;
+        #[repr(align(64))]
+        struct Aligned;
+
+        let bump = Bump::new(64, 8);
+        bump.try_alloc(1_u8).unwrap();
+        let used = bump.snapshot().used;
+        let units: Vec<_> = (0..1000).map(|_| bump.try_alloc(Unit).unwrap()).collect();
+        let aligned = bump.try_alloc(Aligned).ok().unwrap();
+        assert_eq!(BumpMember::as_ptr(&aligned) as usize % 64, 0);
+        let empty = bump.try_alloc_slice_copy::<u64>(&[]).unwrap();
+        assert!(empty.is_empty());
+        let shared = bump.try_alloc_rc(()).unwrap();
+        assert_eq!(bump.snapshot().used, used);
+
+        let filler = bump.try_alloc([0_u8; 56]).unwrap();
+        assert!(bump.try_alloc(Unit).is_ok());
+        drop(bump);
+        drop(filler);
+        assert_eq!(*units[999], Unit);
+        drop((units, aligned, empty, shared));
+    }
+
+    #[test]
+    fn test_alloc_aligned() {
+        let paving = Paving::new(512, 8);
+        paving.try_alloc(1_u8).unwrap();
+        let line = paving.try_alloc_aligned(3_u64, 64).unwrap();
+        assert_eq!(BumpMember::as_ptr(&line) as usize % 64, 0);
+        assert_eq!(*line, 3);
+        let lanes = paving
+            .try_alloc_slice_copy_aligned(&[1.0_f32; 8], 32)
+            .unwrap();
+        assert_eq!(BumpMember::as_ptr(&lanes).cast::<u8>() as usize % 32, 0);
+        assert_eq!(*lanes, [1.0; 8]);
+        assert_eq!(paving.try_alloc_aligned(1_u8, 3).err(), Some(1));
+        // Too large with its padding, so it gets a bump of its own
+        let wide = paving.try_alloc_aligned([0_u8; 384], 256).unwrap();
+        assert_eq!(BumpMember::as_ptr(&wide) as usize % 256, 0);
+    }
+
+    #[test]
+    fn test_fits_new_bump() {
+        let paving = Paving::new(64, 8);
+        paving.try_alloc(1_u8).unwrap();
+        // Values larger than half a bump fit in a new one
+        let large = paving.try_alloc([7_u8; 64]).unwrap();
+        assert_eq!(large[63], 7);
+        // The header of shared values needing drop counts too
+        let shared = paving
+            .try_alloc_rc([String::from("a"), String::new()])
+            .unwrap();
+        assert_eq!(shared[0], "a");
+        let dedicated = paving
+            .try_alloc_rc((String::new(), String::new(), 0_u64))
+            .unwrap();
+        assert!(!same_bump(&dedicated, &shared));
+    }
+
+    #[test]
+    fn test_dedicated_bump() {
+        let paving = Paving::new(64, 8);
+        let small = paving.try_alloc(1_u64).unwrap();
+        let large = paving.try_alloc([2_u64; 100]).unwrap();
+        let next = paving.try_alloc(3_u64).unwrap();
+        assert!(same_bump(&small, &next));
+        assert!(!same_bump(&small, &large));
+        assert_eq!(paving.chunks_created(), 2);
+        assert_eq!(paving.chunks().count(), 2);
+        drop(large);
+        assert_eq!(paving.chunks().count(), 1);
+    }
+
+    #[test]
+    fn test_bump_introspection() {
+        let bump = Bump::new(64, 8);
+        assert_eq!(
+            (bump.capacity(), bump.used(), bump.remaining()),
+            (64, 0, 64)
+        );
+        let _one = bump.try_alloc(1_u8).unwrap();
+        let _two = bump.try_alloc(2_u32).unwrap();
+        // Padding counts as used
+        assert_eq!((bump.used(), bump.remaining()), (8, 56));
+
+        let regions = bump.split(&[16, 8]).unwrap();
+        assert_eq!(regions[0].capacity(), 16);
+        let _three = regions[1].try_alloc(3_u16).unwrap();
+        assert_eq!((regions[1].used(), regions[1].remaining()), (2, 6));
+        assert_eq!(bump.used() + bump.remaining(), bump.capacity());
+    }
 }
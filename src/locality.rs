@@ -0,0 +1,96 @@
+use std::{collections::HashSet, ops::Deref, ptr::NonNull};
+
+use crate::{metadata::Metadata, Member};
+
+/// A type whose values point to other members, and can
+/// report them for analysis.
+pub trait Trace {
+    /// Calls [`Tracer::edge`] on every member directly pointed to by `self`.
+    fn trace(&self, tracer: &mut Tracer);
+}
+
+/// Statistics about the locality of a graph of members,
+/// as returned by [`locality_report`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LocalityReport {
+    /// The number of distinct members reached
+    pub nodes: usize,
+    /// The number of edges between members
+    pub edges: usize,
+    /// The number of edges between members living in different chunks
+    pub cross_chunk_edges: usize,
+    /// The sum of the distances, in bytes, between the members of every edge
+    pub total_distance: usize,
+}
+
+impl LocalityReport {
+    /// The fraction of edges crossing chunk boundaries
+    pub fn cross_chunk_ratio(&self) -> f64 {
+        if self.edges == 0 {
+            0.
+        } else {
+            self.cross_chunk_edges as f64 / self.edges as f64
+        }
+    }
+
+    /// The average distance, in bytes, between the members of an edge
+    pub fn average_distance(&self) -> f64 {
+        if self.edges == 0 {
+            0.
+        } else {
+            self.total_distance as f64 / self.edges as f64
+        }
+    }
+}
+
+/// The visitor walking a graph of members, see [`Trace`].
+pub struct Tracer {
+    report: LocalityReport,
+    visited: HashSet<usize>,
+    current: Option<(usize, NonNull<Metadata>)>,
+}
+
+impl Tracer {
+    /// Records the edge from the member being traced to `member`,
+    /// and traces it if it was not reached yet.
+    pub fn edge<M, T>(&mut self, member: &M)
+    where
+        M: Member + Deref<Target = T>,
+        T: Trace + ?Sized,
+    {
+        let target = (&**member as *const T).cast::<u8>() as usize;
+        let metadata = member.metadata();
+        if let Some((source, source_metadata)) = self.current {
+            self.report.edges += 1;
+            if source_metadata != metadata {
+                self.report.cross_chunk_edges += 1;
+            }
+            self.report.total_distance += source.abs_diff(target);
+        }
+        if self.visited.insert(target) {
+            self.report.nodes += 1;
+            let previous = self.current.replace((target, metadata));
+            member.trace(self);
+            self.current = previous;
+        }
+    }
+}
+
+/// Walks the graph of members reachable from `root`, and reports how well
+/// it is laid out in memory.
+///
+/// This helps evaluating whether the capacity of the bumps is suitable
+/// for the allocation patterns of the graph.
+pub fn locality_report<M, T>(root: &M) -> LocalityReport
+where
+    M: Member + Deref<Target = T>,
+    T: Trace + ?Sized,
+{
+    let mut tracer = Tracer {
+        report: LocalityReport::default(),
+        visited: HashSet::new(),
+        current: None,
+    };
+    tracer.edge(root);
+    tracer.report
+}
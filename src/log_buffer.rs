@@ -0,0 +1,137 @@
+use std::{
+    io::{self, ErrorKind, Write},
+    mem::ManuallyDrop,
+    ptr::{self, drop_in_place},
+};
+
+use crate::{BumpBytes, ChunkPool, NewBumpError};
+
+/// A buffer accumulating records in bump chunks, and writing each
+/// chunk to a sink once full.
+///
+/// Chunks are recycled through a [`ChunkPool`], so no memory is
+/// allocated once the buffer runs in a steady state.
+/// Buffered records are flushed when the buffer is dropped, ignoring errors.
+pub struct BumpLogBuffer<W: Write> {
+    sink: W,
+    pool: ChunkPool,
+    current: Option<BumpBytes>,
+}
+
+impl<W: Write> BumpLogBuffer<W> {
+    /// Try to create a new buffer writing to `sink` by chunks of
+    /// `chunk_capacity` bytes.
+    ///
+    /// See [`Bump::try_new`](`crate::Bump::try_new`).
+    pub fn try_new(sink: W, chunk_capacity: usize) -> Result<Self, NewBumpError> {
+        Ok(Self {
+            sink,
+            pool: ChunkPool::try_new(chunk_capacity, 1, 1)?,
+            current: None,
+        })
+    }
+
+    /// Creates a new buffer writing to `sink` by chunks of
+    /// `chunk_capacity` bytes.
+    ///
+    /// See [`Bump::new`](`crate::Bump::new`).
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(sink: W, chunk_capacity: usize) -> Self {
+        Self::try_new(sink, chunk_capacity).unwrap_or_else(|err| err.panic())
+    }
+
+    /// Appends a record to the buffer, writing the current chunk to the
+    /// sink first if the record does not fit in it.
+    ///
+    /// Records larger than a chunk are written to the sink directly.
+    pub fn write_record(&mut self, record: &[u8]) -> io::Result<()> {
+        if self.try_buffer(record)? {
+            return Ok(());
+        }
+        self.write_chunk()?;
+        if !self.try_buffer(record)? {
+            self.sink.write_all(record)?;
+        }
+        Ok(())
+    }
+
+    /// The records buffered and not written yet
+    pub fn buffered(&self) -> &[u8] {
+        self.current.as_deref().unwrap_or_default()
+    }
+
+    /// The sink of the buffer
+    pub fn get_ref(&self) -> &W {
+        &self.sink
+    }
+
+    /// The sink of the buffer.
+    ///
+    /// Writing to it directly may interleave with the buffered records.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.sink
+    }
+
+    /// Writes the buffered records, then returns the sink.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.write_chunk()?;
+        let mut this = ManuallyDrop::new(self);
+        // Safety:
+        // this is never used again, nor dropped, so the sink is read only
+        // once and the other fields are dropped only once
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        unsafe {
+            drop_in_place(&mut this.current);
+            drop_in_place(&mut this.pool);
+            Ok(ptr::read(&this.sink))
+        }
+    }
+
+    // Appends the record to the current chunk, returning false if it does
+    // not fit in it
+    fn try_buffer(&mut self, record: &[u8]) -> io::Result<bool> {
+        let current = match &mut self.current {
+            Some(current) => current,
+            None => {
+                let bytes = self
+                    .pool
+                    .try_bump()
+                    .ok()
+                    .and_then(|bump| bump.try_alloc_bump_bytes(&[]))
+                    .ok_or(io::Error::from(ErrorKind::OutOfMemory))?;
+                self.current.insert(bytes)
+            }
+        };
+        Ok(current.try_push_bytes(record).is_ok())
+    }
+
+    // Writes the current chunk to the sink, handing it back to the pool
+    fn write_chunk(&mut self) -> io::Result<()> {
+        if let Some(current) = &self.current {
+            self.sink.write_all(current)?;
+            self.current = None;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for BumpLogBuffer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_record(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_chunk()?;
+        self.sink.flush()
+    }
+}
+
+impl<W: Write> Drop for BumpLogBuffer<W> {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
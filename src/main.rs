@@ -1,3 +1,6 @@
+// The example uses the panicking constructors
+#![cfg_attr(feature = "panic_free", allow(deprecated))]
+
 use std::{collections::HashMap, mem::align_of};
 
 use rc_bump::{Paving, RcBumpMember};
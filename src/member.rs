@@ -0,0 +1,118 @@
+use std::ptr::NonNull;
+
+#[cfg(feature = "provenance")]
+use crate::Provenance;
+use crate::{metadata::Metadata, Bump, BumpMember, ChunkInfo, Paving, RcBumpMember};
+
+pub trait Sealed {
+    fn metadata(&self) -> NonNull<Metadata>;
+
+    // The address of the pointee
+    #[cfg(feature = "provenance")]
+    fn data(&self) -> NonNull<u8>;
+}
+
+/// A pointer into a [`Bump`], keeping it alive.
+pub trait Member: Sealed {
+    /// Returns a description of the chunk the pointee lives in.
+    fn chunk(&self) -> ChunkInfo {
+        // Safety: metadata is kept alive by self
+        unsafe { self.metadata().as_ref() }.info()
+    }
+
+    /// Returns where the pointee lives in the arena.
+    #[cfg(feature = "provenance")]
+    fn provenance(&self) -> Provenance {
+        // Safety: metadata is kept alive by self
+        let metadata = unsafe { self.metadata().as_ref() };
+        Provenance {
+            chunk: metadata.id,
+            offset: self.data().as_ptr() as usize - metadata.beg.as_ptr() as usize,
+        }
+    }
+}
+
+impl<M: Sealed> Member for M {}
+
+/// Returns true if both members live in the same bump.
+pub fn same_bump(a: &impl Member, b: &impl Member) -> bool {
+    a.metadata() == b.metadata()
+}
+
+impl Bump {
+    /// Returns true if `ptr` points into the memory of this bump.
+    pub fn contains<T: ?Sized>(&self, ptr: *const T) -> bool {
+        // Safety: metadata is valid as long as self lives
+        let beg = unsafe { self.metadata().as_ref() }.beg.as_ptr() as usize;
+        let end = self.metadata().as_ptr() as usize;
+        (beg..end).contains(&(ptr.cast::<u8>() as usize))
+    }
+
+    // A new handle to the bump the member lives in
+    pub(crate) fn of_member(member: &impl Member) -> Bump {
+        let metadata = member.metadata();
+        // Safety: metadata is kept alive by member
+        unsafe { Metadata::increment(metadata) };
+        // Safety: the handle owns the count just added
+        unsafe { Bump::from_metadata(metadata) }
+    }
+}
+
+impl Paving {
+    /// Try to allocate an object in the bump `near` lives in, falling back
+    /// to the current bump of the paving.
+    ///
+    /// This helps keeping objects which are used together on the same pages,
+    /// even if they were allocated far apart in time.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the object
+    pub fn try_alloc_near<T>(&self, near: &impl Member, value: T) -> Result<BumpMember<T>, T> {
+        match Bump::of_member(near).try_alloc(value) {
+            Ok(member) => Ok(member),
+            Err(value) => self.try_alloc(value),
+        }
+    }
+
+    /// Try to allocate an object with shared ownership in the bump `near`
+    /// lives in, falling back to the current bump of the paving.
+    ///
+    /// See [`Paving::try_alloc_near`].
+    pub fn try_alloc_rc_near<T>(&self, near: &impl Member, value: T) -> Result<RcBumpMember<T>, T> {
+        match Bump::of_member(near).try_alloc_rc(value) {
+            Ok(member) => Ok(member),
+            Err(value) => self.try_alloc_rc(value),
+        }
+    }
+}
+
+impl<T: Clone> BumpMember<T> {
+    /// Try to clone the member into the bump it lives in, falling back
+    /// to the current bump of the paving.
+    ///
+    /// See [`Paving::try_alloc_near`].
+    pub fn try_clone_in(&self, paving: &Paving) -> Result<BumpMember<T>, T> {
+        paving.try_alloc_near(self, T::clone(self))
+    }
+}
+
+/// Clones the value into the bump of the member if there is room left,
+/// and into a bump of its own otherwise.
+///
+/// # Panics
+///
+/// Panics if the bump of the clone cannot be created.
+///
+/// Trait implementations cannot be deprecated, so this one is kept by the
+/// `panic_free` feature: see [`BumpMember::try_clone_in`] instead.
+#[cfg_attr(feature = "panic_free", allow(clippy::panic, deprecated))]
+impl<T: Clone> Clone for BumpMember<T> {
+    fn clone(&self) -> Self {
+        match Bump::of_member(self).try_alloc(T::clone(self)) {
+            Ok(member) => member,
+            Err(value) => Bump::new(std::mem::size_of::<T>().max(1), std::mem::align_of::<T>())
+                .try_alloc(value)
+                .unwrap_or_else(|_| panic!("Could not allocate the clone of a member")),
+        }
+    }
+}
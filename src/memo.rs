@@ -0,0 +1,131 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+};
+
+use crate::{NewBumpError, Paving, RcBumpMember};
+
+// The cached values, and their keys from the least to the most recently used
+struct Entries<K, V> {
+    values: HashMap<K, (RcBumpMember<V>, u64)>,
+    recency: BTreeMap<u64, K>,
+    tick: u64,
+}
+
+impl<K: Eq + Hash + Clone, V> Entries<K, V> {
+    fn touch(&mut self, key: &K) -> Option<RcBumpMember<V>> {
+        let (value, used) = self.values.get_mut(key)?;
+        self.recency.remove(used);
+        self.tick += 1;
+        *used = self.tick;
+        self.recency.insert(self.tick, key.clone());
+        Some(value.clone())
+    }
+}
+
+/// A cache of computed values, stored with shared ownership in a paving.
+///
+/// Once the cache holds `max_entries` values, the least recently used one is
+/// evicted. Evicted values stay alive as long as members point to them.
+pub struct MemoCache<K, V> {
+    paving: Paving,
+    max_entries: usize,
+    entries: RefCell<Entries<K, V>>,
+}
+
+impl<K: Eq + Hash + Clone, V> MemoCache<K, V> {
+    /// Try to create a new cache holding at most `max_entries` values,
+    /// whose backing bumps will have the corresponding capacity and align.
+    ///
+    /// See [`Bump::try_new`](`crate::Bump::try_new`).
+    pub fn try_new(
+        capacity: usize,
+        align: usize,
+        max_entries: usize,
+    ) -> Result<Self, NewBumpError> {
+        Ok(Self {
+            paving: Paving::try_new(capacity, align)?,
+            max_entries,
+            entries: RefCell::new(Entries {
+                values: HashMap::new(),
+                recency: BTreeMap::new(),
+                tick: 0,
+            }),
+        })
+    }
+
+    /// Creates a new cache holding at most `max_entries` values,
+    /// whose backing bumps will have the corresponding capacity and align.
+    ///
+    /// See [`Bump::new`](`crate::Bump::new`).
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(capacity: usize, align: usize, max_entries: usize) -> Self {
+        Self::try_new(capacity, align, max_entries).unwrap_or_else(|err| err.panic())
+    }
+
+    /// Returns the cached value of `key`, if any.
+    pub fn get(&self, key: &K) -> Option<RcBumpMember<V>> {
+        self.entries.try_borrow_mut().ok()?.touch(key)
+    }
+
+    /// Returns the cached value of `key`, computing and caching it
+    /// with `compute` if needed.
+    ///
+    /// Fails, giving back the computed value, if it cannot be allocated
+    /// or if called from `compute` or from the `Hash` or `Eq` implementation
+    /// of `K`.
+    pub fn try_get_or_insert_with(
+        &self,
+        key: K,
+        compute: impl FnOnce(&K) -> V,
+    ) -> Result<RcBumpMember<V>, V> {
+        if let Some(value) = self.get(&key) {
+            return Ok(value);
+        }
+        // The cache is not borrowed while running user code
+        let value = compute(&key);
+        let Ok(mut entries) = self.entries.try_borrow_mut() else {
+            return Err(value);
+        };
+        // compute may have cached the value itself
+        if let Some(existing) = entries.touch(&key) {
+            drop(entries);
+            drop(value);
+            return Ok(existing);
+        }
+        let member = self.paving.try_alloc_rc(value)?;
+        let mut evicted = Vec::new();
+        while entries.values.len() >= self.max_entries {
+            let Some((_, oldest)) = entries.recency.pop_first() else {
+                break;
+            };
+            evicted.extend(entries.values.remove(&oldest));
+        }
+        if self.max_entries > 0 {
+            entries.tick += 1;
+            let tick = entries.tick;
+            entries.recency.insert(tick, key.clone());
+            evicted.extend(entries.values.insert(key, (member.clone(), tick)));
+        }
+        drop(entries);
+        // Evicted values are dropped once the cache is released
+        drop(evicted);
+        Ok(member)
+    }
+
+    /// Returns the number of values cached
+    pub fn len(&self) -> usize {
+        self.entries
+            .try_borrow()
+            .map_or(0, |entries| entries.values.len())
+    }
+
+    /// Returns true if no value is cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,211 @@
+use std::{
+    alloc::{dealloc, Layout},
+    cell::Cell,
+    ptr::NonNull,
+    rc::Rc,
+    sync::atomic::{fence, AtomicBool, AtomicU64, Ordering},
+};
+
+use crate::{
+    chunks::{ChunkInfo, ChunkLink},
+    pool::PoolInner,
+};
+
+/// The allocation state of a region of a bump
+pub(crate) struct Cursor {
+    /// The first byte of the right, non allocated part of the region
+    pub(crate) first_free: Cell<NonNull<u8>>,
+    /// The right limit of the region
+    pub(crate) end: NonNull<u8>,
+}
+
+// Metadata is public so that it can appear in the signature of
+// member::Sealed, but this module is private so it is not nameable
+// outside of the crate.
+
+/// The metadata of a Bump
+pub struct Metadata {
+    /// The number of pointer keeping this bump alive.
+    /// It is only updated with atomic operations once the bump is shared.
+    pub(crate) count: AtomicU64,
+    /// Whether pointers to the bump may be released from other threads,
+    /// as [`crate::ArcBumpMember`]s and [`crate::SyncBump`]s do.
+    /// It is set before any such pointer exists, and never unset.
+    pub(crate) shared: AtomicBool,
+    /// The beginning of the Bump containing this Metadata
+    pub(crate) beg: NonNull<u8>,
+    /// The Layout that was obtained from [`Bump::inner_layout`]
+    pub(crate) layout: Layout,
+    /// The cursor of the main region of the bump, ending at the metadata
+    pub(crate) cursor: Cursor,
+    /// The link to the other bumps of the paving this bump belongs to
+    pub(crate) link: ChunkLink,
+    /// The pool the memory of the bump is returned to once deallocated
+    pub(crate) pool: Cell<Option<Rc<PoolInner>>>,
+    /// The identifier of the chunk, see [`crate::Provenance`]
+    #[cfg(feature = "provenance")]
+    pub(crate) id: u64,
+    /// Whether the memory of the bump was locked in RAM
+    #[cfg(feature = "mlock")]
+    pub(crate) locked: Cell<bool>,
+    /// The end address of the pages of the bump made read-only,
+    /// or 0 if none is
+    #[cfg(feature = "mprotect")]
+    pub(crate) protected_end: Cell<usize>,
+    /// Whether the memory of the bump must be wiped
+    #[cfg(feature = "zeroize")]
+    pub(crate) sensitive: Cell<bool>,
+}
+
+impl Metadata {
+    // # Safety
+    // - sself must not be dangling
+    pub(crate) unsafe fn increment(sself: NonNull<Self>) {
+        Self::increment_by(sself, 1);
+    }
+
+    // # Safety
+    // - sself must not be dangling
+    pub(crate) unsafe fn increment_by(sself: NonNull<Self>, n: u64) {
+        let sself = sself.as_ref();
+        if sself.is_shared() {
+            sself.count.fetch_add(n, Ordering::Relaxed);
+        } else {
+            // Only this thread can reach the bump, so a plain update is enough
+            let count = sself.count.load(Ordering::Relaxed);
+            sself.count.store(count + n, Ordering::Relaxed);
+        }
+    }
+
+    // Whether pointers to the bump may be released from other threads
+    pub(crate) fn is_shared(&self) -> bool {
+        // Relaxed is enough: the flag is set before any pointer is sent
+        // to another thread, which synchronizes with it
+        self.shared.load(Ordering::Relaxed)
+    }
+
+    // Lets pointers to the bump be released from other threads.
+    // Must be called before any of them is sent to another thread.
+    pub(crate) fn share(&self) {
+        if !self.is_shared() {
+            self.shared.store(true, Ordering::Relaxed);
+        }
+    }
+
+    // # Safety
+    // - sself must not be dangling
+    // - No live reference to sself pointee must exist
+    pub(crate) unsafe fn decrement_and_drop(sself: NonNull<Self>) {
+        let count = &sself.as_ref().count;
+        let last = if sself.as_ref().is_shared() {
+            // Same orderings as std::sync::Arc, so that every use of the
+            // bump happens before its deallocation
+            let last = count.fetch_sub(1, Ordering::Release) == 1;
+            if last {
+                fence(Ordering::Acquire);
+            }
+            last
+        } else {
+            let remaining = count.load(Ordering::Relaxed) - 1;
+            count.store(remaining, Ordering::Relaxed);
+            remaining == 0
+        };
+        if last {
+            ChunkLink::unlink(sself);
+            #[cfg(feature = "mprotect")]
+            crate::mprotect::unprotect(sself.as_ref());
+            #[cfg(feature = "zeroize")]
+            crate::sensitive::wipe_chunk(sself.as_ref());
+            #[cfg(feature = "mlock")]
+            crate::mlock::unlock(sself.as_ref());
+            // It is ok to dealloc or recycle because nobody references
+            // this chunk anymore
+            let beg = sself.as_ref().beg;
+            match sself.as_ref().pool.take() {
+                Some(pool) => pool.recycle(beg),
+                None => dealloc(beg.as_ptr(), sself.as_ref().layout),
+            }
+        }
+    }
+
+    // Extends the allocation ending at `end` by `additional` bytes, if it is
+    // the last one of the main region of the bump and there is enough room.
+    pub(crate) fn try_extend(&self, end: NonNull<u8>, additional: usize) -> bool {
+        if additional == 0 {
+            return true;
+        }
+        let cursor = &self.cursor;
+        if cursor.first_free.get() != end {
+            return false;
+        }
+        if additional > cursor.end.as_ptr() as usize - end.as_ptr() as usize {
+            return false;
+        }
+        // Safety: end + additional is within the memory of the bump
+        #[allow(clippy::multiple_unsafe_ops_per_block)]
+        let new_end = unsafe { NonNull::new_unchecked(end.as_ptr().add(additional)) };
+        cursor.first_free.set(new_end);
+        true
+    }
+
+    // Gives back the memory between `start` and `end` to the main region of
+    // the bump, if it is its last allocation.
+    // The memory must not be used anymore.
+    pub(crate) fn try_rewind(&self, start: NonNull<u8>, end: NonNull<u8>) -> bool {
+        let cursor = &self.cursor;
+        if cursor.first_free.get() != end {
+            return false;
+        }
+        // Read-only memory cannot be handed out again
+        #[cfg(feature = "mprotect")]
+        if (start.as_ptr() as usize) < self.protected_end.get() {
+            return false;
+        }
+        cursor.first_free.set(start);
+        true
+    }
+
+    // Grows or shrinks the allocation of old_size bytes at ptr to new_size
+    // bytes, if it is the last one of the main region of the bump.
+    //
+    // # Safety
+    // - ptr must point to old_size bytes allocated in the bump
+    // - the bytes past new_size must not be used anymore
+    pub(crate) unsafe fn try_resize(
+        &self,
+        ptr: NonNull<u8>,
+        old_size: usize,
+        new_size: usize,
+    ) -> bool {
+        let end = NonNull::new_unchecked(ptr.as_ptr().add(old_size));
+        match new_size.checked_sub(old_size) {
+            Some(additional) => self.try_extend(end, additional),
+            None => self.try_rewind(NonNull::new_unchecked(ptr.as_ptr().add(new_size)), end),
+        }
+    }
+
+    // Whether the bump belongs to no paving nor pool, whose bookkeeping
+    // is not thread safe
+    pub(crate) fn is_standalone(&self) -> bool {
+        let pool = self.pool.take();
+        let standalone = pool.is_none() && !self.link.is_linked();
+        self.pool.set(pool);
+        standalone
+    }
+
+    // The number of bytes available for allocations in the chunk,
+    // which the metadata ends
+    pub(crate) fn capacity(&self) -> usize {
+        self as *const Metadata as usize - self.beg.as_ptr() as usize
+    }
+
+    pub(crate) fn info(&self) -> ChunkInfo {
+        let beg = self.beg.as_ptr();
+        let used = self.cursor.first_free.get().as_ptr() as usize - beg as usize;
+        ChunkInfo {
+            base: beg,
+            capacity: self.capacity(),
+            used: 0..used,
+        }
+    }
+}
@@ -1,9 +1,13 @@
 use std::{
+    alloc::Layout,
+    mem::{needs_drop, size_of},
     ops::{Deref, DerefMut},
     rc::Rc,
 };
 
-use crate::{BumpMember, Paving, RcBumpMember};
+use crate::{
+    bump::RcHeader, paving::fits_new_bump, BumpMember, NewBumpError, Paving, RcBumpMember,
+};
 
 /// A pointer to a mixed paving owning its pointee
 pub enum OwnedMixedPavingMember<T> {
@@ -52,29 +56,118 @@ impl<T> Deref for SharedMixedPavingMember<T> {
     }
 }
 
+/// Where a [`MixedPaving`] allocates a given object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AllocPolicy {
+    /// In a bump if it is small enough, on its own otherwise.
+    ///
+    /// See [`MixedPaving::with_spill_threshold`].
+    #[default]
+    Auto,
+    /// In a bump, of its own if the object is too large
+    ForceBump,
+    /// On its own
+    ForceHeap,
+}
+
 /// A paving which will allocate objects too large out of any bump
-pub struct MixedPaving(Paving);
+pub struct MixedPaving {
+    paving: Paving,
+    // The size above which objects are allocated on their own, if not
+    // the size of the bumps
+    spill_threshold: Option<usize>,
+}
 
 impl MixedPaving {
+    /// Try to create a new mixed paving whose backing bumps will have the corresponding
+    /// capacity and align.
+    ///
+    /// See [`Bump::try_new`](`crate::Bump::try_new`).
+    pub fn try_new(capacity: usize, align: usize) -> Result<Self, NewBumpError> {
+        Paving::try_new(capacity, align).map(Self::from_paving)
+    }
+
     /// Creates a new mixed paving whose backing bumps will have the corresponding
     /// capacity and align.
     ///
     /// See [`Bump::new`](`crate::Bump::new`).
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
     pub fn new(capacity: usize, align: usize) -> Self {
-        Self(Paving::new(capacity, align))
+        Self::try_new(capacity, align).unwrap_or_else(|err| err.panic())
+    }
+
+    fn from_paving(paving: Paving) -> Self {
+        Self {
+            paving,
+            spill_threshold: None,
+        }
+    }
+
+    /// Allocates objects larger than `size` bytes on their own, and
+    /// smaller ones in a bump, of their own if they are too large for
+    /// the paving.
+    ///
+    /// By default, objects are allocated on their own when they are too
+    /// large for the bumps of the paving.
+    pub fn with_spill_threshold(mut self, size: usize) -> Self {
+        self.spill_threshold = Some(size);
+        self
     }
 
     /// Alloc an object returning an owning pointer
     pub fn alloc<T>(&self, value: T) -> OwnedMixedPavingMember<T> {
-        match self.0.try_alloc(value) {
+        self.alloc_with_policy(value, AllocPolicy::Auto)
+    }
+
+    /// Alloc an object return an shareable pointer
+    pub fn alloc_rc<T>(&self, value: T) -> SharedMixedPavingMember<T> {
+        self.alloc_rc_with_policy(value, AllocPolicy::Auto)
+    }
+
+    // Whether AllocPolicy::Auto allocates an object of the size in a bump,
+    // the layout being the room it takes there
+    fn fits(&self, size: usize, layout: Layout) -> bool {
+        match self.spill_threshold {
+            Some(threshold) => size <= threshold,
+            None => fits_new_bump(layout, self.paving.chunk_capacity(), self.paving.align()),
+        }
+    }
+
+    /// Alloc an object returning an owning pointer, as directed by `policy`.
+    ///
+    /// With [`AllocPolicy::ForceBump`], the object still ends up on its own
+    /// if no bump can be created for it.
+    pub fn alloc_with_policy<T>(&self, value: T, policy: AllocPolicy) -> OwnedMixedPavingMember<T> {
+        let res = match policy {
+            AllocPolicy::Auto if !self.fits(size_of::<T>(), Layout::new::<T>()) => Err(value),
+            // The paving gives objects too large a bump of their own
+            AllocPolicy::Auto | AllocPolicy::ForceBump => self.paving.try_alloc(value),
+            AllocPolicy::ForceHeap => Err(value),
+        };
+        match res {
             Ok(sm) => OwnedMixedPavingMember::BumpMember(sm),
             Err(val) => OwnedMixedPavingMember::Box(Box::new(val)),
         }
     }
 
-    /// Alloc an object return an shareable pointer
-    pub fn alloc_rc<T>(&self, value: T) -> SharedMixedPavingMember<T> {
-        match self.0.try_alloc_rc(value) {
+    /// Alloc an object returning a shareable pointer, as directed by `policy`.
+    ///
+    /// See [`MixedPaving::alloc_with_policy`].
+    pub fn alloc_rc_with_policy<T>(
+        &self,
+        value: T,
+        policy: AllocPolicy,
+    ) -> SharedMixedPavingMember<T> {
+        let layout = RcHeader::entry_layout(Layout::new::<T>(), needs_drop::<T>());
+        let res = match policy {
+            AllocPolicy::Auto if !self.fits(size_of::<T>(), layout) => Err(value),
+            AllocPolicy::Auto | AllocPolicy::ForceBump => self.paving.try_alloc_rc(value),
+            AllocPolicy::ForceHeap => Err(value),
+        };
+        match res {
             Ok(sm) => SharedMixedPavingMember::RcBumpMember(sm),
             Err(val) => SharedMixedPavingMember::Rc(Rc::new(val)),
         }
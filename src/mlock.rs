@@ -0,0 +1,58 @@
+use crate::{metadata::Metadata, Bump};
+
+#[cfg(unix)]
+impl Metadata {
+    // The memory of the bump, as a pointer and a length
+    fn data_range(&self) -> (*const libc::c_void, usize) {
+        let beg = self.beg.as_ptr();
+        let len = self as *const Metadata as usize - beg as usize;
+        (beg.cast(), len)
+    }
+}
+
+// Unlocks the memory of a bump about to be deallocated
+pub(crate) fn unlock(metadata: &Metadata) {
+    #[cfg(unix)]
+    if metadata.locked.get() {
+        let (addr, len) = metadata.data_range();
+        // Safety: the range is the memory of the bump, which is still allocated
+        unsafe { libc::munlock(addr, len) };
+    }
+}
+
+impl Bump {
+    /// Locks the memory of this bump in RAM, so that accessing its members
+    /// never triggers a page fault.
+    ///
+    /// Locking may fail, for instance because of the `RLIMIT_MEMLOCK` limit
+    /// or on platforms other than unix, in which case the bump keeps working with unlocked memory and this
+    /// returns false.
+    ///
+    /// Memory is locked with page granularity, and unlocked when the bump
+    /// is deallocated, which may also unlock the pages it shares with other
+    /// allocations.
+    pub fn lock_memory(&self) -> bool {
+        // Safety: metadata is valid as long as self lives
+        let metadata = unsafe { self.metadata().as_ref() };
+        if !metadata.locked.get() {
+            #[cfg(unix)]
+            let locked = {
+                let (addr, len) = metadata.data_range();
+                // Safety: the range is the memory of the bump, which is allocated
+                unsafe { libc::mlock(addr, len) == 0 }
+            };
+            #[cfg(not(unix))]
+            let locked = false;
+            metadata.locked.set(locked);
+        }
+        metadata.locked.get()
+    }
+
+    /// Returns true if the memory of this bump was locked in RAM.
+    ///
+    /// See [`Bump::lock_memory`].
+    pub fn is_memory_locked(&self) -> bool {
+        // Safety: metadata is valid as long as self lives
+        unsafe { self.metadata().as_ref() }.locked.get()
+    }
+}
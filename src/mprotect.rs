@@ -0,0 +1,104 @@
+use crate::{metadata::Metadata, Bump};
+
+#[cfg(unix)]
+impl Metadata {
+    // The whole pages of the bump before the address `end`, if any
+    fn pages_before(&self, end: usize) -> Option<(*mut libc::c_void, usize)> {
+        // Safety: sysconf has no precondition
+        let page = match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+            page if page > 0 => page as usize,
+            _ => return None,
+        };
+        let beg = self.beg.as_ptr() as usize;
+        let start = beg.checked_next_multiple_of(page)?;
+        let end = end / page * page;
+        if start >= end {
+            return None;
+        }
+        // start is within the bump, as it is before end
+        let ptr = self.beg.as_ptr().wrapping_add(start - beg);
+        Some((ptr.cast(), end - start))
+    }
+
+    // Makes the pages of the bump writable again
+    fn unprotect(&self) {
+        if let Some((start, len)) = self.pages_before(self.protected_end.replace(0)) {
+            // Safety: the range was protected by protect_read_only
+            unsafe { libc::mprotect(start, len, libc::PROT_READ | libc::PROT_WRITE) };
+        }
+    }
+}
+
+// Makes the memory of a bump about to be deallocated writable again
+pub(crate) fn unprotect(metadata: &Metadata) {
+    #[cfg(unix)]
+    metadata.unprotect();
+    #[cfg(not(unix))]
+    let _ = metadata;
+}
+
+impl Bump {
+    /// Makes the memory already allocated in this bump read-only, so that
+    /// any mutation of it faults immediately.
+    ///
+    /// Memory is protected with page granularity, so the allocations sharing
+    /// a page with free memory or with the metadata of the bump are not.
+    /// New objects can still be allocated, in free memory.
+    ///
+    /// The bump is unprotected before being deallocated.
+    ///
+    /// Returns false if no memory was protected, for instance on platforms
+    /// other than unix.
+    ///
+    /// # Safety
+    ///
+    /// The memory already allocated must not be written to until the bump
+    /// is unprotected, which would fault. This includes mutating values
+    /// through their members or through interior mutability, and using
+    /// members which write next to their value, such as cloning or dropping
+    /// [`RcBumpMember`](crate::RcBumpMember)s of types needing drop, or
+    /// [`ArcBumpMember`](crate::ArcBumpMember)s, and allocating in regions
+    /// [split](Bump::split) from this bump.
+    pub unsafe fn protect_read_only(&self) -> bool {
+        // Safety: metadata is valid as long as self lives
+        let metadata = unsafe { self.metadata().as_ref() };
+        #[cfg(unix)]
+        {
+            let first_free = metadata.cursor.first_free.get().as_ptr() as usize;
+            let Some((start, len)) = metadata.pages_before(first_free) else {
+                return false;
+            };
+            let end = start as usize + len;
+            if end <= metadata.protected_end.get() {
+                return false;
+            }
+            // Safety: the range is made of whole pages of the bump
+            if unsafe { libc::mprotect(start, len, libc::PROT_READ) } != 0 {
+                return false;
+            }
+            metadata.protected_end.set(end);
+            true
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = metadata;
+            false
+        }
+    }
+
+    /// Makes the memory of this bump writable again.
+    ///
+    /// See [`Bump::protect_read_only`].
+    pub fn unprotect(&self) {
+        // Safety: metadata is valid as long as self lives
+        unprotect(unsafe { self.metadata().as_ref() })
+    }
+
+    /// Returns true if some memory of this bump is read-only.
+    ///
+    /// See [`Bump::protect_read_only`].
+    pub fn is_protected(&self) -> bool {
+        // Safety: metadata is valid as long as self lives
+        unsafe { self.metadata().as_ref() }.protected_end.get() > 0
+    }
+}
@@ -1,49 +1,674 @@
-use std::{cell::UnsafeCell, mem::size_of};
+use std::{
+    alloc::Layout,
+    cell::{Cell, RefCell, UnsafeCell},
+    collections::VecDeque,
+    error::Error,
+    fmt,
+    mem::{align_of, needs_drop, replace, MaybeUninit},
+    rc::Rc,
+};
 
-use crate::{Bump, BumpMember, RcBumpMember};
+#[cfg(feature = "failpoints")]
+use crate::failpoints::FailPoints;
+use crate::{
+    adaptive::AllocStats, bump::RcHeader, chunks::ChunkList, Bump, BumpMember, ChunkPool,
+    NewBumpError, RcBumpMember, WeakBumpMember,
+};
+
+/// When a [`Paving`] abandons its current bump because an
+/// allocation did not fit in it.
+///
+/// A bump which is not abandoned stays the current one, and the
+/// object which did not fit is allocated in a bump of its own.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RetirementPolicy {
+    /// Always abandon the current bump
+    #[default]
+    Always,
+    /// Abandon the current bump only if at least this fraction
+    /// of its capacity is allocated
+    MinUtilization(f64),
+    /// Abandon the current bump only if less than this
+    /// number of bytes remain free in it
+    MinRemainingBytes(usize),
+}
+
+/// How the capacity of the successive bumps of a [`Paving`] grows
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum GrowthStrategy {
+    /// Every bump has the capacity of the first one
+    #[default]
+    Fixed,
+    /// Every bump is twice as large as the previous one,
+    /// up to this capacity
+    Doubling(usize),
+    /// Every bump is `factor` times as large as the previous one,
+    /// up to `max` bytes
+    Geometric {
+        /// The growth of the capacity from one bump to the next
+        factor: f64,
+        /// The largest capacity of a bump
+        max: usize,
+    },
+}
+
+impl GrowthStrategy {
+    // The capacity of the bump following one of the given capacity,
+    // which is never smaller
+    fn next_capacity(self, capacity: usize) -> usize {
+        let grown = match self {
+            GrowthStrategy::Fixed => return capacity,
+            GrowthStrategy::Doubling(max) => capacity.saturating_mul(2).min(max),
+            GrowthStrategy::Geometric { factor, max } => {
+                // The cast saturates on overflow
+                ((capacity as f64 * factor) as usize).min(max)
+            }
+        };
+        grown.max(capacity)
+    }
+}
+
+/// Why an allocation in a [`Paving`] failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AllocErrorKind {
+    /// The object does not fit in the bumps of the paving, nor in a bump
+    /// of its own
+    TooLarge,
+    /// No new bump could be created to hold the object
+    NewBump(NewBumpError),
+    /// The failure was injected for testing purposes,
+    /// with the `failpoints` feature
+    Injected,
+    /// A new bump would exceed the maximum number of chunks
+    /// of the paving
+    LimitReached,
+    /// A new bump would exceed the memory budget of the paving
+    BudgetExceeded,
+}
+
+/// An allocation failure in a [`Paving`], giving back the object
+/// which could not be allocated.
+#[derive(Debug)]
+pub struct AllocError<T> {
+    value: T,
+    kind: AllocErrorKind,
+}
+
+impl<T> AllocError<T> {
+    /// Why the allocation failed
+    pub fn kind(&self) -> AllocErrorKind {
+        self.kind
+    }
+
+    /// Returns the object which could not be allocated
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl fmt::Display for AllocErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AllocErrorKind::TooLarge => f.write_str("the object is too large for the paving"),
+            AllocErrorKind::NewBump(_) => f.write_str("no new bump could be created"),
+            AllocErrorKind::Injected => f.write_str("injected allocation failure"),
+            AllocErrorKind::LimitReached => {
+                f.write_str("the paving reached its maximum number of chunks")
+            }
+            AllocErrorKind::BudgetExceeded => {
+                f.write_str("the paving would exceed its memory budget")
+            }
+        }
+    }
+}
+
+impl Error for AllocErrorKind {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AllocErrorKind::NewBump(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl<T> fmt::Display for AllocError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "allocation failed: {}", self.kind)
+    }
+}
+
+impl<T: fmt::Debug> Error for AllocError<T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.kind.source()
+    }
+}
 
 /// A structure generating bumps as appropriated
 pub struct Paving {
-    capacity: usize,
+    capacity: Cell<usize>,
     align: usize,
     current_bump: UnsafeCell<Bump>,
+    // The last bumps abandoned by the paving, the most recent first
+    recent: RefCell<VecDeque<Bump>>,
+    max_recent: usize,
+    chunks: Rc<ChunkList>,
+    pool: Option<ChunkPool>,
+    retirement: RetirementPolicy,
+    growth: GrowthStrategy,
+    limits: ChunkLimits,
+    adaptive: Option<AllocStats>,
+    chunks_created: Cell<usize>,
+    #[cfg(feature = "failpoints")]
+    failpoints: FailPoints,
+    #[cfg(feature = "mlock")]
+    lock_memory: bool,
+    #[cfg(feature = "zeroize")]
+    sensitive: bool,
+}
+
+// The most chunks and bytes the live chunks of a paving may hold
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ChunkLimits {
+    pub(crate) chunks: Option<usize>,
+    pub(crate) bytes: Option<usize>,
+}
+
+// Whether a value of the layout fits in any new bump of the capacity and
+// align, whatever padding it needs there.
+pub(crate) fn fits_new_bump(layout: Layout, capacity: usize, align: usize) -> bool {
+    let padding = layout.align().saturating_sub(align);
+    layout
+        .size()
+        .checked_add(padding)
+        .is_some_and(|size| size <= capacity)
 }
 
 impl Paving {
+    /// Try to create a new paving, which will be backed by bumps
+    /// created with correponding capacity and align.
+    ///
+    /// See [`Bump::try_new`]
+    pub fn try_new(capacity: usize, align: usize) -> Result<Self, NewBumpError> {
+        let chunks = Rc::<ChunkList>::default();
+        let first_bump = Self::new_bump(&chunks, capacity, align, None)?;
+        Ok(Self {
+            capacity: capacity.into(),
+            align,
+            current_bump: first_bump.into(),
+            recent: RefCell::default(),
+            max_recent: 0,
+            chunks,
+            pool: None,
+            retirement: RetirementPolicy::Always,
+            growth: GrowthStrategy::Fixed,
+            limits: ChunkLimits::default(),
+            adaptive: None,
+            chunks_created: 1.into(),
+            #[cfg(feature = "failpoints")]
+            failpoints: FailPoints::default(),
+            #[cfg(feature = "mlock")]
+            lock_memory: false,
+            #[cfg(feature = "zeroize")]
+            sensitive: false,
+        })
+    }
+
+    // See ChunkPool::try_paving
+    pub(crate) fn try_from_pool(pool: ChunkPool) -> Result<Self, NewBumpError> {
+        let chunks = Rc::<ChunkList>::default();
+        let (capacity, align) = (pool.capacity(), pool.align());
+        let first_bump = Self::new_bump(&chunks, capacity, align, Some(&pool))?;
+        Ok(Self {
+            capacity: capacity.into(),
+            align,
+            current_bump: first_bump.into(),
+            recent: RefCell::default(),
+            max_recent: 0,
+            chunks,
+            pool: Some(pool),
+            retirement: RetirementPolicy::Always,
+            growth: GrowthStrategy::Fixed,
+            limits: ChunkLimits::default(),
+            adaptive: None,
+            chunks_created: 1.into(),
+            #[cfg(feature = "failpoints")]
+            failpoints: FailPoints::default(),
+            #[cfg(feature = "mlock")]
+            lock_memory: false,
+            #[cfg(feature = "zeroize")]
+            sensitive: false,
+        })
+    }
+
     /// Creates a new paving, which will be backed by bumps
     /// created with correponding capacity and align.
     ///
     /// See [`Bump::new`]
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
     pub fn new(capacity: usize, align: usize) -> Self {
-        let first_bump = Bump::new(capacity, align);
-        Self {
-            capacity,
-            align,
-            current_bump: first_bump.into(),
+        Self::try_new(capacity, align).unwrap_or_else(|err| err.panic())
+    }
+
+    /// Sets when the current bump is abandoned for a fresh one.
+    ///
+    /// See [`RetirementPolicy`].
+    pub fn with_retirement_policy(mut self, policy: RetirementPolicy) -> Self {
+        self.retirement = policy;
+        self
+    }
+
+    /// Sets how the capacity of the next bumps grows.
+    ///
+    /// This has no effect on pavings drawing their bumps from a
+    /// [`ChunkPool`], whose capacity is fixed, nor on pavings with
+    /// [adaptive sizing](Paving::with_adaptive_sizing).
+    pub fn with_growth_strategy(mut self, strategy: GrowthStrategy) -> Self {
+        self.growth = strategy;
+        self
+    }
+
+    /// Makes allocations fail with [`AllocErrorKind::BudgetExceeded`]
+    /// rather than create a bump which would bring the capacity of the
+    /// live chunks of the paving over `budget` bytes.
+    pub fn with_memory_budget(mut self, budget: usize) -> Self {
+        self.limits.bytes = Some(budget);
+        self
+    }
+
+    /// Keeps the last `n` bumps abandoned by the paving, and tries to
+    /// allocate in them before creating a new bump, so that less room
+    /// is wasted at their end when objects of various sizes are allocated.
+    ///
+    /// These bumps stay alive as long as they are kept.
+    pub fn with_recent_chunks(mut self, n: usize) -> Self {
+        self.max_recent = n;
+        self
+    }
+
+    /// Lets the paving pick the capacity of its next bumps from
+    /// the sizes of the objects allocated lately, so that they fit
+    /// about 64 objects of average size.
+    ///
+    /// Objects too large for the current capacity are then accepted
+    /// as well, by creating larger bumps.
+    ///
+    /// This has no effect on pavings drawing their bumps from a
+    /// [`ChunkPool`], whose capacity is fixed.
+    pub fn with_adaptive_sizing(mut self) -> Self {
+        self.adaptive = Some(AllocStats::default());
+        self
+    }
+
+    /// Makes every `n`th allocation in the paving fail, for testing
+    /// purposes. A null `n` disables this.
+    #[cfg(feature = "failpoints")]
+    pub fn with_failure_every(mut self, n: usize) -> Self {
+        self.failpoints.every = n;
+        self
+    }
+
+    /// Makes allocations in the paving fail once they would exceed
+    /// `budget` bytes in total, for testing purposes.
+    #[cfg(feature = "failpoints")]
+    pub fn with_byte_budget(mut self, budget: usize) -> Self {
+        self.failpoints.budget = Some(budget);
+        self
+    }
+
+    /// Locks the memory of the bumps of this paving in RAM.
+    ///
+    /// See [`Bump::lock_memory`].
+    #[cfg(feature = "mlock")]
+    pub fn with_locked_memory(mut self) -> Self {
+        self.lock_memory = true;
+        self.current_bump.get_mut().lock_memory();
+        self
+    }
+
+    /// Marks the bumps of this paving as holding sensitive data.
+    ///
+    /// See [`Bump::mark_sensitive`].
+    #[cfg(feature = "zeroize")]
+    pub fn with_sensitive_memory(mut self) -> Self {
+        self.sensitive = true;
+        self.current_bump.get_mut().mark_sensitive();
+        self
+    }
+
+    // See PavingBuilder
+    pub(crate) fn with_chunk_limits(mut self, limits: ChunkLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    // The bump replacing the current one once it is full
+    fn next_bump(&self) -> Result<Bump, AllocErrorKind> {
+        let capacity = self.next_capacity();
+        let bump = self.add_bump(capacity, self.align, self.pool.as_ref())?;
+        self.capacity.set(capacity);
+        Ok(bump)
+    }
+
+    // The capacity of the bump replacing the current one
+    fn next_capacity(&self) -> usize {
+        match (&self.adaptive, &self.pool) {
+            (Some(stats), None) => stats.capacity(),
+            (None, None) => self.growth.next_capacity(self.capacity.get()),
+            (_, Some(_)) => self.capacity.get(),
         }
     }
 
+    /// Makes sure the current bump has at least `bytes` bytes free,
+    /// replacing it with a large enough one if needed, so that a burst
+    /// of allocations does not create a bump midway.
+    ///
+    /// Padding between objects takes room as well, which should be
+    /// accounted for in `bytes`. See also [`Paving::assert_no_new_chunks`].
+    pub fn try_reserve(&self, bytes: usize) -> Result<(), AllocErrorKind> {
+        // Safety: there is no other active reference
+        if unsafe { &*self.current_bump.get() }.remaining() >= bytes {
+            return Ok(());
+        }
+        let next_capacity = self.next_capacity();
+        let bump = match &self.pool {
+            Some(_) if bytes > next_capacity => return Err(AllocErrorKind::TooLarge),
+            pool => self.add_bump(next_capacity.max(bytes), self.align, pool.as_ref())?,
+        };
+        self.capacity.set(next_capacity);
+        // Safety: there is no other active reference
+        let old = replace(unsafe { &mut *self.current_bump.get() }, bump);
+        self.retain_recent(old);
+        Ok(())
+    }
+
+    // Keeps a bump abandoned by the paving among the recent ones
+    fn retain_recent(&self, bump: Bump) {
+        if self.max_recent == 0 {
+            return;
+        }
+        let mut recent = self.recent.borrow_mut();
+        recent.push_front(bump);
+        recent.truncate(self.max_recent);
+    }
+
+    // Runs `alloc` on the recent bumps, from the most recent one
+    fn alloc_in_recent<V, R>(
+        &self,
+        mut value: V,
+        alloc: &impl Fn(&Bump, V) -> Result<R, V>,
+    ) -> Result<R, V> {
+        for i in 0..self.max_recent {
+            // Cloned so that alloc does not run with the ring borrowed
+            let Some(bump) = self.recent.borrow().get(i).cloned() else {
+                break;
+            };
+            value = match alloc(&bump, value) {
+                Ok(res) => return Ok(res),
+                Err(value) => value,
+            };
+        }
+        Err(value)
+    }
+
+    // Creates a bump of the paving, set up like the others
+    fn add_bump(
+        &self,
+        capacity: usize,
+        align: usize,
+        pool: Option<&ChunkPool>,
+    ) -> Result<Bump, AllocErrorKind> {
+        self.check_limits(capacity)?;
+        let bump =
+            Self::new_bump(&self.chunks, capacity, align, pool).map_err(AllocErrorKind::NewBump)?;
+        self.chunks_created.set(self.chunks_created.get() + 1);
+        #[cfg(feature = "mlock")]
+        if self.lock_memory {
+            bump.lock_memory();
+        }
+        #[cfg(feature = "zeroize")]
+        if self.sensitive {
+            bump.mark_sensitive();
+        }
+        Ok(bump)
+    }
+
+    fn new_bump(
+        chunks: &Rc<ChunkList>,
+        capacity: usize,
+        align: usize,
+        pool: Option<&ChunkPool>,
+    ) -> Result<Bump, NewBumpError> {
+        let bump = match pool {
+            Some(pool) => pool.try_bump()?,
+            None => Bump::try_new(capacity, align)?,
+        };
+        // Safety: the bump was just created and is alive
+        unsafe { chunks.link(bump.metadata()) };
+        Ok(bump)
+    }
+
+    // Fails if a new bump of the capacity would exceed the limits
+    // of the paving
+    fn check_limits(&self, capacity: usize) -> Result<(), AllocErrorKind> {
+        let ChunkLimits { chunks, bytes } = self.limits;
+        if chunks.is_none() && bytes.is_none() {
+            return Ok(());
+        }
+        let (live_chunks, live_capacity) = self.chunk_list().totals();
+        if chunks.is_some_and(|max| live_chunks >= max) {
+            return Err(AllocErrorKind::LimitReached);
+        }
+        if bytes.is_some_and(|max| live_capacity.saturating_add(capacity) > max) {
+            return Err(AllocErrorKind::BudgetExceeded);
+        }
+        Ok(())
+    }
+
+    fn should_retire(&self, bump: &Bump) -> bool {
+        match self.retirement {
+            RetirementPolicy::Always => true,
+            RetirementPolicy::MinUtilization(min) => {
+                // Safety: metadata is valid as long as bump lives
+                let info = unsafe { bump.metadata().as_ref() }.info();
+                info.used.len() as f64 >= min * info.capacity as f64
+            }
+            RetirementPolicy::MinRemainingBytes(min) => bump.remaining() < min,
+        }
+    }
+
+    /// The capacity of the bumps created by the paving
+    pub fn chunk_capacity(&self) -> usize {
+        self.capacity.get()
+    }
+
+    // The alignment of the bumps created by the paving
+    pub(crate) fn align(&self) -> usize {
+        self.align
+    }
+
+    /// The number of bumps created by the paving so far, including
+    /// the first one
+    pub fn chunks_created(&self) -> usize {
+        self.chunks_created.get()
+    }
+
+    /// Runs `f`, checking that it does not make the paving create a new bump.
+    ///
+    /// This allows verifying that a paving reserved upfront is large enough
+    /// for a critical section, such as a real-time audio callback.
+    ///
+    /// # Panics
+    ///
+    /// Panics once `f` returns if a bump was created.
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn assert_no_new_chunks<R>(&self, f: impl FnOnce() -> R) -> R {
+        let before = self.chunks_created();
+        let res = f();
+        let created = self.chunks_created() - before;
+        assert!(
+            created == 0,
+            "{created} bumps were created in a section which must not allocate"
+        );
+        res
+    }
+
+    pub(crate) fn chunk_list(&self) -> &ChunkList {
+        &self.chunks
+    }
+
+    /// Runs `alloc` on the current bump, and if it fails, on a fresh one.
+    ///
+    /// An object of the given layout too large for the bumps of this
+    /// paving gets a bump of its own. Fails if no new bump can be created.
+    pub(crate) fn try_alloc_inner<V, R>(
+        &self,
+        layout: Layout,
+        value: V,
+        alloc: impl Fn(&Bump, V) -> Result<R, V>,
+    ) -> Result<R, V> {
+        self.try_alloc_reporting(layout, value, alloc)
+            .map_err(AllocError::into_value)
+    }
+
+    // Same as try_alloc_inner, telling why the allocation failed
+    fn try_alloc_reporting<V, R>(
+        &self,
+        layout: Layout,
+        value: V,
+        alloc: impl Fn(&Bump, V) -> Result<R, V>,
+    ) -> Result<R, AllocError<V>> {
+        match self.admit(layout) {
+            Ok(()) => self.place(layout, value, alloc),
+            Err(kind) => Err(AllocError { value, kind }),
+        }
+    }
+
+    // Accounts for an allocation of the layout, failing if it is
+    // set to fail
+    fn admit(&self, layout: Layout) -> Result<(), AllocErrorKind> {
+        #[cfg(feature = "failpoints")]
+        if self.failpoints.should_fail(layout.size()) {
+            return Err(AllocErrorKind::Injected);
+        }
+        if let Some(stats) = &self.adaptive {
+            stats.record(layout.size());
+        }
+        Ok(())
+    }
+
+    // Same as try_alloc_reporting, once the allocation was admitted
+    fn place<V, R>(
+        &self,
+        layout: Layout,
+        value: V,
+        alloc: impl Fn(&Bump, V) -> Result<R, V>,
+    ) -> Result<R, AllocError<V>> {
+        let fail = |value, kind| Err(AllocError { value, kind });
+        // Safety: there is no other active reference
+        let value = match alloc(unsafe { &*self.current_bump.get() }, value) {
+            Ok(res) => return Ok(res),
+            Err(value) => value,
+        };
+        let value = match self.alloc_in_recent(value, &alloc) {
+            Ok(res) => return Ok(res),
+            Err(value) => value,
+        };
+        // The current bump may have been reserved larger than the others,
+        // and adaptive bumps are sized from the objects but not their padding
+        if !fits_new_bump(layout, self.next_capacity(), self.align()) {
+            return self.alloc_dedicated(layout, value, alloc);
+        }
+        let next_bump = match self.next_bump() {
+            Ok(next_bump) => next_bump,
+            Err(kind) => return fail(value, kind),
+        };
+        // Safety: there is no other active reference
+        let res = if !self.should_retire(unsafe { &*self.current_bump.get() }) {
+            // The bump is kept alive by the allocated object
+            let res = alloc(&next_bump, value);
+            self.retain_recent(next_bump);
+            res
+        } else {
+            // Safety: there is no other active reference
+            let old = replace(unsafe { &mut *self.current_bump.get() }, next_bump);
+            self.retain_recent(old);
+            // Safety: there is no other active reference
+            alloc(unsafe { &*self.current_bump.get() }, value)
+        };
+        debug_assert!(res.is_ok());
+        res.or_else(|value| fail(value, AllocErrorKind::TooLarge))
+    }
+
+    // Allocates a value too large for the bumps of the paving in a bump
+    // sized for it, which stays alive as long as the value only.
+    fn alloc_dedicated<V, R>(
+        &self,
+        layout: Layout,
+        value: V,
+        alloc: impl Fn(&Bump, V) -> Result<R, V>,
+    ) -> Result<R, AllocError<V>> {
+        let align = layout.align().max(self.align);
+        let bump = match self.add_bump(layout.size(), align, None) {
+            Ok(bump) => bump,
+            Err(kind) => return Err(AllocError { value, kind }),
+        };
+        alloc(&bump, value).map_err(|value| AllocError {
+            value,
+            kind: AllocErrorKind::TooLarge,
+        })
+    }
+
     /// Try to allocate an object in the paving
     ///
     /// Fails if no bump big enough can be created to accomodate
     /// the object
     pub fn try_alloc<T>(&self, value: T) -> Result<BumpMember<T>, T> {
-        if size_of::<T>() * 2 > self.capacity {
-            return Err(value);
-        }
+        self.try_alloc_inner(Layout::new::<T>(), value, Bump::try_alloc)
+    }
 
-        // Safety: there is no other active reference
-        match unsafe { (*self.current_bump.get()).try_alloc(value) } {
-            Ok(sm) => Ok(sm),
-            Err(value) => {
-                // Safety: there is no other active reference
-                unsafe { *self.current_bump.get() = Bump::new(self.capacity, self.align) };
-                // Safety: there is no other active reference
-                let res = unsafe { (*self.current_bump.get()).try_alloc(value) };
-                debug_assert!(res.is_ok());
-                res
-            }
-        }
+    /// Try to allocate an object built by a closure in the paving,
+    /// building it in place.
+    ///
+    /// See [`Bump::try_alloc_with`].
+    pub fn try_alloc_with<T, F: FnOnce() -> T>(&self, build: F) -> Result<BumpMember<T>, F> {
+        self.try_alloc_inner(Layout::new::<T>(), build, Bump::try_alloc_with)
+    }
+
+    /// Try to allocate room for an object in the paving, to be initialized
+    /// in place.
+    ///
+    /// See [`Bump::try_alloc_uninit`].
+    pub fn try_alloc_uninit<T>(&self) -> Option<BumpMember<MaybeUninit<T>>> {
+        self.try_alloc_inner(Layout::new::<T>(), (), |bump, ()| {
+            bump.try_alloc_uninit().ok_or(())
+        })
+        .ok()
+    }
+
+    /// Try to allocate an object built by a fallible closure in the paving,
+    /// building it in place.
+    ///
+    /// See [`Bump::try_alloc_try_with`].
+    pub fn try_alloc_try_with<T, E, F>(&self, build: F) -> Result<Result<BumpMember<T>, E>, F>
+    where
+        F: FnOnce() -> Result<T, E>,
+    {
+        self.try_alloc_inner(Layout::new::<T>(), build, Bump::try_alloc_try_with)
+    }
+
+    /// Try to allocate an object in the paving, telling why it failed
+    /// along with the object otherwise.
+    ///
+    /// See [`Paving::try_alloc`].
+    pub fn try_alloc_detailed<T>(&self, value: T) -> Result<BumpMember<T>, AllocError<T>> {
+        self.try_alloc_reporting(Layout::new::<T>(), value, Bump::try_alloc)
     }
 
     /// Try to allocate a object with shared ownership in the bump.
@@ -51,21 +676,285 @@ impl Paving {
     /// Fails if no bump big enough can be created to accomodate
     /// the object
     pub fn try_alloc_rc<T>(&self, value: T) -> Result<RcBumpMember<T>, T> {
-        if size_of::<T>() * 2 > self.capacity {
+        self.try_alloc_inner(
+            RcHeader::entry_layout(Layout::new::<T>(), needs_drop::<T>()),
+            value,
+            Bump::try_alloc_rc,
+        )
+    }
+
+    /// Try to allocate an object with shared ownership built by a closure
+    /// in the paving, building it in place.
+    ///
+    /// See [`Bump::try_alloc_rc_with`].
+    pub fn try_alloc_rc_with<T, F: FnOnce() -> T>(&self, build: F) -> Result<RcBumpMember<T>, F> {
+        self.try_alloc_inner(
+            RcHeader::entry_layout(Layout::new::<T>(), needs_drop::<T>()),
+            build,
+            Bump::try_alloc_rc_with,
+        )
+    }
+
+    /// Try to allocate an object with shared ownership in the paving,
+    /// telling why it failed along with the object otherwise.
+    ///
+    /// See [`Paving::try_alloc_rc`].
+    pub fn try_alloc_rc_detailed<T>(&self, value: T) -> Result<RcBumpMember<T>, AllocError<T>> {
+        self.try_alloc_reporting(
+            RcHeader::entry_layout(Layout::new::<T>(), needs_drop::<T>()),
+            value,
+            Bump::try_alloc_rc,
+        )
+    }
+
+    /// Try to allocate an object with shared ownership, built by a closure
+    /// given a weak pointer to it.
+    ///
+    /// See [`Bump::try_alloc_rc_cyclic`].
+    pub fn try_alloc_rc_cyclic<T, F>(&self, build: F) -> Result<RcBumpMember<T>, F>
+    where
+        F: FnOnce(&WeakBumpMember<T>) -> T,
+    {
+        let layout = RcHeader::entry_layout(Layout::new::<T>(), true);
+        self.try_alloc_inner(layout, build, Bump::try_alloc_rc_cyclic)
+    }
+
+    /// Try to allocate an object in the paving, at an address multiple of
+    /// `align`.
+    ///
+    /// See [`Bump::try_alloc_aligned`].
+    pub fn try_alloc_aligned<T>(&self, value: T, align: usize) -> Result<BumpMember<T>, T> {
+        let Ok(layout) = Layout::new::<T>().align_to(align) else {
             return Err(value);
+        };
+        self.try_alloc_inner(layout, value, |bump, value| {
+            bump.try_alloc_aligned(value, align)
+        })
+    }
+
+    /// Try to copy a slice into the paving, with a single copy.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the slice
+    pub fn try_alloc_slice_copy<T: Copy>(&self, src: &[T]) -> Option<BumpMember<[T]>> {
+        self.try_alloc_slice_copy_aligned(src, align_of::<T>())
+    }
+
+    /// Try to copy a slice into the paving, at an address multiple of
+    /// `align`.
+    ///
+    /// See [`Bump::try_alloc_slice_copy_aligned`].
+    pub fn try_alloc_slice_copy_aligned<T: Copy>(
+        &self,
+        src: &[T],
+        align: usize,
+    ) -> Option<BumpMember<[T]>> {
+        let layout = Layout::for_value(src).align_to(align).ok()?;
+        self.try_alloc_inner(layout, (), |bump, ()| {
+            bump.try_alloc_slice_copy_aligned(src, align).ok_or(())
+        })
+        .ok()
+    }
+
+    /// Try to allocate room for a slice of `len` elements in the paving,
+    /// to be initialized in place.
+    ///
+    /// See [`Bump::try_alloc_slice_uninit`].
+    pub fn try_alloc_slice_uninit<T>(&self, len: usize) -> Option<BumpMember<[MaybeUninit<T>]>> {
+        let layout = Layout::array::<T>(len).ok()?;
+        self.try_alloc_inner(layout, (), |bump, ()| {
+            bump.try_alloc_slice_uninit(len).ok_or(())
+        })
+        .ok()
+    }
+
+    /// Try to clone the elements of a slice into the paving.
+    ///
+    /// If cloning an element panics, the elements already cloned are dropped.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the slice
+    pub fn try_alloc_slice_clone<T: Clone>(&self, src: &[T]) -> Option<BumpMember<[T]>> {
+        let mut src_iter = src.iter();
+        self.try_fill_slice(src.len(), move || src_iter.next().cloned())
+    }
+
+    /// Try to allocate a slice of `len` elements, each produced by calling
+    /// `f` with its index.
+    ///
+    /// If `f` panics, the elements already produced are dropped.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the slice
+    pub fn try_alloc_slice_fill_with<T>(
+        &self,
+        len: usize,
+        mut f: impl FnMut(usize) -> T,
+    ) -> Option<BumpMember<[T]>> {
+        let mut index = 0;
+        self.try_fill_slice(len, move || {
+            let value = f(index);
+            index += 1;
+            Some(value)
+        })
+    }
+
+    /// Try to allocate a slice of `len` default elements.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the slice
+    pub fn try_alloc_slice_fill_default<T: Default>(&self, len: usize) -> Option<BumpMember<[T]>> {
+        self.try_alloc_slice_fill_with(len, |_| T::default())
+    }
+
+    /// Try to allocate a slice of `len` copies of `value`.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the slice
+    pub fn try_alloc_slice_fill_copy<T: Copy>(
+        &self,
+        len: usize,
+        value: T,
+    ) -> Option<BumpMember<[T]>> {
+        self.try_alloc_slice_fill_with(len, |_| value)
+    }
+
+    /// Try to allocate a slice holding the values of an iterator, written in
+    /// place in room reserved for its announced length.
+    ///
+    /// If the iterator yields fewer values than announced, the slice is
+    /// shorter and the room left is given back, and values past the announced
+    /// length are ignored. If it panics, the values already written are dropped.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the slice
+    pub fn try_alloc_from_iter<I>(&self, values: I) -> Option<BumpMember<[I::Item]>>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut values = values.into_iter();
+        self.try_fill_slice(values.len(), move || values.next())
+    }
+
+    // See Bump::try_fill_slice
+    fn try_fill_slice<T>(
+        &self,
+        len: usize,
+        next: impl FnMut() -> Option<T>,
+    ) -> Option<BumpMember<[T]>> {
+        let layout = Layout::array::<T>(len).ok()?;
+        self.try_alloc_inner(layout, next, |bump, next| bump.try_fill_slice(len, next))
+            .ok()
+    }
+
+    /// Try to copy a string into the paving.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the string
+    pub fn try_alloc_str(&self, s: &str) -> Option<BumpMember<str>> {
+        self.try_alloc_inner(Layout::for_value(s), (), |bump, ()| {
+            bump.try_alloc_str(s).ok_or(())
+        })
+        .ok()
+    }
+
+    /// Try to allocate all the values of an iterator.
+    ///
+    /// Values are written back to back, and both the capacity and the count
+    /// of the current bump are updated once for as many values as fit in it,
+    /// rather than once per value.
+    ///
+    /// Fails if a value cannot be allocated, giving back all the values in
+    /// order.
+    pub fn try_alloc_iter<I, T>(&self, values: I) -> Result<Vec<BumpMember<T>>, Vec<T>>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut values = values.into_iter();
+        if !self.admit_batch(Layout::new::<T>(), values.len()) {
+            return Err(values.collect());
         }
+        let mut members = Vec::with_capacity(values.len());
+        loop {
+            // Safety: there is no other active reference
+            unsafe { &*self.current_bump.get() }.alloc_batch(&mut values, &mut members);
+            // The current bump is full, let the next value open a new one
+            let Some(value) = values.next() else {
+                return Ok(members);
+            };
+            match self.place(Layout::new::<T>(), value, Bump::try_alloc) {
+                Ok(member) => members.push(member),
+                Err(err) => {
+                    let value = err.into_value();
+                    let mut given_back: Vec<T> =
+                        members.into_iter().map(BumpMember::take).collect();
+                    given_back.push(value);
+                    given_back.extend(values);
+                    return Err(given_back);
+                }
+            }
+        }
+    }
 
-        // Safety: there is no other active reference
-        match unsafe { (*self.current_bump.get()).try_alloc_rc(value) } {
-            Ok(sm) => Ok(sm),
-            Err(value) => {
-                // Safety: there is no other active reference
-                unsafe { *self.current_bump.get() = Bump::new(self.capacity, self.align) };
-                // Safety: there is no other active reference
-                let res = unsafe { (*self.current_bump.get()).try_alloc_rc(value) };
-                debug_assert!(res.is_ok());
-                res
+    /// Try to allocate all the values of an iterator with shared ownership.
+    ///
+    /// Values are written back to back, and the capacity of the current bump
+    /// is checked once for as many values as fit in it, rather than once per
+    /// value.
+    ///
+    /// Fails if a value cannot be allocated, giving back all the values in
+    /// order.
+    pub fn try_alloc_iter_rc<I, T>(&self, values: I) -> Result<Vec<RcBumpMember<T>>, Vec<T>>
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let layout = RcHeader::entry_layout(Layout::new::<T>(), needs_drop::<T>());
+        let mut values = values.into_iter();
+        if !self.admit_batch(layout, values.len()) {
+            return Err(values.collect());
+        }
+        let mut members = Vec::with_capacity(values.len());
+        loop {
+            // Safety: there is no other active reference
+            unsafe { &*self.current_bump.get() }.alloc_rc_batch(&mut values, &mut members);
+            // The current bump is full, let the next value open a new one
+            let Some(value) = values.next() else {
+                return Ok(members);
+            };
+            match self.place(layout, value, Bump::try_alloc_rc) {
+                Ok(member) => members.push(member),
+                Err(err) => {
+                    let value = err.into_value();
+                    let mut given_back: Vec<T> = members
+                        .into_iter()
+                        // Safety: the members were just created and not shared
+                        .map(|member| unsafe { member.into_inner_unique() })
+                        .collect();
+                    given_back.push(value);
+                    given_back.extend(values);
+                    return Err(given_back);
+                }
             }
         }
     }
+
+    // Accounts for count allocations of the layout, as a batch
+    // which fails as a whole if one of them is set to fail
+    fn admit_batch(&self, layout: Layout, count: usize) -> bool {
+        let mut admitted = true;
+        for _ in 0..count {
+            admitted &= self.admit(layout).is_ok();
+        }
+        admitted
+    }
+
+    /// Try to allocate clones of the values of a slice with shared ownership.
+    ///
+    /// See [`Paving::try_alloc_iter_rc`].
+    pub fn try_alloc_slice_rc<T: Clone>(&self, values: &[T]) -> Option<Vec<RcBumpMember<T>>> {
+        self.try_alloc_iter_rc(values.iter().cloned()).ok()
+    }
 }
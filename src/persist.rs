@@ -0,0 +1,406 @@
+use std::{
+    collections::HashMap,
+    io::{self, Read, Write},
+    mem::{align_of, size_of},
+    slice,
+};
+
+use crate::{member::Sealed, BumpMember, ChunkInfo, Member, NewBumpError, Paving};
+
+/// Plain old data, which can be written as bytes and read back.
+///
+/// # Safety
+///
+/// The type must have no padding, hold no pointer, and every bit
+/// pattern must be a valid value, like integers and floats.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! pod {
+    ($($ty:ty),*) => {$(
+        // Safety: primitive numbers have no padding and no invalid values
+        unsafe impl Pod for $ty {}
+    )*};
+}
+
+pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+// Safety: arrays have no padding between their elements
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+/// Plain old data which can be converted between byte orders, so that
+/// dumps of a [`PodPaving`] can be reloaded on platforms with another one.
+///
+/// See [`PodPaving::read_portable_from`].
+pub trait PortablePod: Pod {
+    /// Reverses the byte order of every field of the value.
+    fn swap_bytes(&mut self);
+}
+
+macro_rules! portable_int {
+    ($($ty:ty),*) => {$(
+        impl PortablePod for $ty {
+            fn swap_bytes(&mut self) {
+                *self = <$ty>::swap_bytes(*self);
+            }
+        }
+    )*};
+}
+
+portable_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl PortablePod for f32 {
+    fn swap_bytes(&mut self) {
+        *self = f32::from_bits(self.to_bits().swap_bytes());
+    }
+}
+
+impl PortablePod for f64 {
+    fn swap_bytes(&mut self) {
+        *self = f64::from_bits(self.to_bits().swap_bytes());
+    }
+}
+
+impl<T: PortablePod, const N: usize> PortablePod for [T; N] {
+    fn swap_bytes(&mut self) {
+        self.iter_mut().for_each(T::swap_bytes);
+    }
+}
+
+/// The key of a value of a [`PodPaving`], which stays valid once the
+/// paving is reloaded, unlike its address.
+///
+/// Values can refer to each other through their keys, to persist graphs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct PodKey(u64);
+
+// Safety: it is a plain u64
+unsafe impl Pod for PodKey {}
+
+impl PortablePod for PodKey {
+    fn swap_bytes(&mut self) {
+        self.0 = self.0.swap_bytes();
+    }
+}
+
+/// A paving of plain values which can be written to a byte stream and
+/// reloaded later, for instance to cache a large graph on disk.
+///
+/// See [`PodPaving::write_to`] and [`PodPaving::read_from`].
+///
+/// # Format
+///
+/// Dumps do not depend on the size of `usize`: every integer is written as
+/// an 8-byte little-endian unsigned integer, in this order:
+///
+/// 1. the magic bytes `RCBUMPD1`;
+/// 2. `0x0102030405060708`, in the byte order of the platform which wrote
+///    the values;
+/// 3. the size and align of `T`, then the capacity and align of the chunks;
+/// 4. the number of values, then for each of them, by key, the index of its
+///    chunk and its offset in the bytes of this chunk;
+/// 5. the number of chunks, then for each of them the number of bytes it
+///    holds, followed by these bytes.
+///
+/// The bytes of a chunk are the ones from its first value to its last one,
+/// as they are in memory, so that values keep their relative positions.
+/// A dump can be reloaded on any platform where `T` has the same size and
+/// align, by [`PodPaving::read_from`] if it has the same byte order, and by
+/// [`PodPaving::read_portable_from`] otherwise.
+pub struct PodPaving<T: Pod> {
+    paving: Paving,
+    // The values, in allocation order, indexed by their keys
+    values: Vec<BumpMember<T>>,
+}
+
+const MAGIC: [u8; 8] = *b"RCBUMPD1";
+
+// Written in the byte order of the platform, to detect dumps whose values
+// were written with another one
+const BYTE_ORDER_MARK: u64 = 0x0102_0304_0506_0708;
+
+impl<T: Pod> PodPaving<T> {
+    /// Try to create a new paving of plain values, whose backing bumps will
+    /// have the corresponding capacity and align.
+    ///
+    /// See [`Bump::try_new`](`crate::Bump::try_new`).
+    pub fn try_new(capacity: usize, align: usize) -> Result<Self, NewBumpError> {
+        Ok(Self {
+            paving: Paving::try_new(capacity, align)?,
+            values: Vec::new(),
+        })
+    }
+
+    /// Creates a new paving of plain values, whose backing bumps will have
+    /// the corresponding capacity and align.
+    ///
+    /// See [`Bump::new`](`crate::Bump::new`).
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(capacity: usize, align: usize) -> Self {
+        Self::try_new(capacity, align).unwrap_or_else(|err| err.panic())
+    }
+
+    /// Try to allocate a value, returning its key.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the value
+    pub fn try_alloc(&mut self, value: T) -> Result<PodKey, T> {
+        let member = self.paving.try_alloc(value)?;
+        self.values.push(member);
+        Ok(PodKey(self.values.len() as u64 - 1))
+    }
+
+    /// Returns the value of the key, if it belongs to this paving.
+    pub fn get(&self, key: PodKey) -> Option<&T> {
+        let index = usize::try_from(key.0).ok()?;
+        self.values.get(index).map(|member| &**member)
+    }
+
+    /// Returns the value of the key, if it belongs to this paving.
+    pub fn get_mut(&mut self, key: PodKey) -> Option<&mut T> {
+        let index = usize::try_from(key.0).ok()?;
+        self.values.get_mut(index).map(|member| &mut **member)
+    }
+
+    /// Returns a description of the chunk the value of the key lives in,
+    /// if it belongs to this paving.
+    pub fn chunk(&self, key: PodKey) -> Option<ChunkInfo> {
+        let index = usize::try_from(key.0).ok()?;
+        self.values.get(index).map(Member::chunk)
+    }
+
+    /// The number of values of the paving
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether the paving holds no value
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Writes the chunks of the paving and the index of its values, so that
+    /// it can be reloaded with [`PodPaving::read_from`].
+    ///
+    /// The bytes of every chunk are written at once, as they are in memory.
+    pub fn write_to(&self, mut writer: impl Write) -> io::Result<()> {
+        // The pointers to the first byte of the values of each chunk and
+        // past their last byte, and their number, with chunks in order of
+        // first use, and the chunk and pointer of every value.
+        // The pointers come from the members, so that the bytes of a chunk
+        // can be read through them.
+        let mut chunk_ids = HashMap::new();
+        let mut chunks: Vec<(*const u8, *const u8, usize)> = Vec::new();
+        let mut index = Vec::with_capacity(self.values.len());
+        for member in &self.values {
+            let start = BumpMember::as_ptr(member).cast::<u8>();
+            let end = start.wrapping_add(size_of::<T>());
+            let chunk = *chunk_ids.entry(member.metadata()).or_insert_with(|| {
+                chunks.push((start, end, 0));
+                chunks.len() - 1
+            });
+            if let Some((first, last, values)) = chunks.get_mut(chunk) {
+                *first = (*first).min(start);
+                *last = (*last).max(end);
+                *values += 1;
+            }
+            index.push((chunk, start));
+        }
+        // Safety: the pointers of a chunk point into its memory
+        let len = |&(first, last, _): &(*const u8, *const u8, usize)| unsafe {
+            last.offset_from(first) as usize
+        };
+        // The values of a chunk are allocated back to back, as the paving
+        // holds nothing else and the size of T is a multiple of its align
+        if chunks
+            .iter()
+            .any(|chunk| len(chunk) != chunk.2 * size_of::<T>())
+        {
+            return Err(io::Error::other("the values of a chunk are not contiguous"));
+        }
+
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&BYTE_ORDER_MARK.to_ne_bytes())?;
+        for n in [
+            size_of::<T>(),
+            align_of::<T>(),
+            self.paving.chunk_capacity(),
+            self.paving.align(),
+            index.len(),
+        ] {
+            write_u64(&mut writer, n)?;
+        }
+        for (chunk, start) in index {
+            write_u64(&mut writer, chunk)?;
+            let first = chunks.get(chunk).map_or(start, |&(first, _, _)| first);
+            // Safety: both pointers point into the memory of the chunk
+            write_u64(&mut writer, unsafe { start.offset_from(first) } as usize)?;
+        }
+        write_u64(&mut writer, chunks.len())?;
+        for chunk in chunks {
+            write_u64(&mut writer, len(&chunk))?;
+            // Safety:
+            // the range only holds values of the paving, which are alive,
+            // and whose bytes are all initialized as Pod values have no padding
+            let bytes = unsafe { slice::from_raw_parts(chunk.0, len(&chunk)) };
+            writer.write_all(bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Reloads a paving written by [`PodPaving::write_to`], with the
+    /// same keys for the same values.
+    ///
+    /// The bytes of every chunk are read at once into the new paving,
+    /// so values keep their relative positions within their chunk, and
+    /// [`RelMember`](crate::RelMember)s between them stay valid.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] if the dump is malformed,
+    /// holds values of another type or was written with another byte order,
+    /// and with [`io::ErrorKind::OutOfMemory`] if the values cannot be
+    /// allocated.
+    pub fn read_from(reader: impl Read) -> io::Result<Self> {
+        Self::read_dump(reader, None)
+    }
+
+    /// Reloads a paving written by [`PodPaving::write_to`], possibly on a
+    /// platform with another byte order, whose values are then converted.
+    ///
+    /// See [`PodPaving::read_from`].
+    pub fn read_portable_from(reader: impl Read) -> io::Result<Self>
+    where
+        T: PortablePod,
+    {
+        Self::read_dump(reader, Some(T::swap_bytes))
+    }
+
+    // Reloads a dump, converting its values with swap if it was written
+    // with another byte order
+    fn read_dump(mut reader: impl Read, swap: Option<fn(&mut T)>) -> io::Result<Self> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(invalid_data("not a dump of a PodPaving"));
+        }
+        let mut mark = [0; 8];
+        reader.read_exact(&mut mark)?;
+        let swap = match u64::from_ne_bytes(mark) {
+            BYTE_ORDER_MARK => None,
+            mark if mark == BYTE_ORDER_MARK.swap_bytes() => match swap {
+                Some(swap) => Some(swap),
+                None => return Err(invalid_data("the dump was written with another byte order")),
+            },
+            _ => return Err(invalid_data("invalid byte order mark")),
+        };
+        let size = read_usize(&mut reader)?;
+        let align = read_usize(&mut reader)?;
+        if (size, align) != (size_of::<T>(), align_of::<T>()) {
+            return Err(invalid_data("the dump holds values of another type"));
+        }
+        let capacity = read_usize(&mut reader)?;
+        let chunk_align = read_usize(&mut reader)?;
+        let mut paving = Self::try_new(capacity, chunk_align).map_err(|err| match err {
+            NewBumpError::OutOfMemory => io::ErrorKind::OutOfMemory.into(),
+            _ => invalid_data("invalid capacity or alignment of the chunks"),
+        })?;
+
+        // The values of each chunk, by key
+        let mut chunk_values: Vec<Vec<(usize, usize)>> = Vec::new();
+        let len = read_usize(&mut reader)?;
+        for key in 0..len {
+            let chunk = read_usize(&mut reader)?;
+            let offset = read_usize(&mut reader)?;
+            let known = chunk_values.len();
+            match chunk_values.get_mut(chunk) {
+                Some(values) => values.push((key, offset)),
+                // Chunks are numbered in order of first use
+                None if chunk == known => chunk_values.push(vec![(key, offset)]),
+                None => return Err(invalid_data("chunks are not numbered in order")),
+            }
+        }
+
+        let mut values: Vec<Option<BumpMember<T>>> = Vec::new();
+        values.resize_with(len, || None);
+        let chunks = read_usize(&mut reader)?;
+        if chunks < chunk_values.len() {
+            return Err(invalid_data("a value lies in a missing chunk"));
+        }
+        for chunk in 0..chunks {
+            let chunk_len = read_usize(&mut reader)?;
+            let chunk_values = chunk_values.get(chunk).map_or(&[][..], Vec::as_slice);
+            let mut members =
+                paving.read_chunk(&mut reader, chunk_len, chunk_values.len(), swap)?;
+            for (nth, &(key, offset)) in chunk_values.iter().enumerate() {
+                // Zero-sized values are not told apart by their offsets
+                let position = match size {
+                    0 => Some(nth),
+                    _ => offset.is_multiple_of(size).then_some(offset / size),
+                };
+                let member = position
+                    .and_then(|position| members.get_mut(position))
+                    .and_then(Option::take)
+                    .ok_or_else(|| invalid_data("a value lies outside of its chunk"))?;
+                if let Some(slot) = values.get_mut(key) {
+                    *slot = Some(member);
+                }
+            }
+        }
+        paving.values = values.into_iter().flatten().collect();
+        Ok(paving)
+    }
+
+    // Reads the bytes of a chunk into the paving at once, returning members
+    // to the values it holds.
+    fn read_chunk(
+        &self,
+        reader: &mut impl Read,
+        chunk_len: usize,
+        zero_sized: usize,
+        swap: Option<fn(&mut T)>,
+    ) -> io::Result<Vec<Option<BumpMember<T>>>> {
+        let len = match size_of::<T>() {
+            0 => zero_sized,
+            size if chunk_len.is_multiple_of(size) => chunk_len / size,
+            _ => return Err(invalid_data("a chunk holds a partial value")),
+        };
+        let mut slice = self
+            .paving
+            .try_alloc_slice_uninit::<T>(len)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::OutOfMemory))?;
+        let start = slice.as_mut_ptr().cast::<u8>();
+        // Safety: the slice holds chunk_len bytes
+        unsafe { start.write_bytes(0, chunk_len) };
+        // Safety: the bytes of the slice were just initialized
+        let bytes = unsafe { slice::from_raw_parts_mut(start, chunk_len) };
+        reader.read_exact(bytes)?;
+        // Safety:
+        // the slice holds the bytes of values of T, which are valid whatever
+        // they are as T is Pod
+        let mut slice = unsafe { slice.assume_init_slice() };
+        if let Some(swap) = swap {
+            slice.iter_mut().for_each(swap);
+        }
+        Ok(slice.into_members().into_iter().map(Some).collect())
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn write_u64(writer: &mut impl Write, n: usize) -> io::Result<()> {
+    writer.write_all(&(n as u64).to_le_bytes())
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn read_usize(reader: &mut impl Read) -> io::Result<usize> {
+    usize::try_from(read_u64(reader)?).map_err(|_| invalid_data("the dump does not fit in memory"))
+}
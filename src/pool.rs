@@ -0,0 +1,141 @@
+use std::{
+    alloc::{alloc, dealloc, Layout},
+    cell::RefCell,
+    ptr::NonNull,
+    rc::Rc,
+};
+
+use crate::{Bump, NewBumpError, Paving};
+
+/// A pool of chunks of memory, from which child pavings borrow
+/// their bumps.
+///
+/// Once deallocated, the bumps of the children are handed back to the
+/// pool for reuse, rather than to the global allocator.
+///
+/// Cloning a pool returns a new handle to the same pool.
+#[derive(Clone)]
+pub struct ChunkPool {
+    inner: Rc<PoolInner>,
+}
+
+pub(crate) struct PoolInner {
+    capacity: usize,
+    align: usize,
+    layout: Layout,
+    metadata_offset: usize,
+    free: RefCell<Vec<NonNull<u8>>>,
+}
+
+impl ChunkPool {
+    /// Try to create a new pool of chunks, backing bumps with the
+    /// corresponding capacity and align, reserving `reserved` chunks
+    /// upfront.
+    ///
+    /// See [`Bump::try_new`].
+    pub fn try_new(capacity: usize, align: usize, reserved: usize) -> Result<Self, NewBumpError> {
+        if capacity == 0 {
+            return Err(NewBumpError::NullCapacity);
+        }
+        let (layout, metadata_offset) =
+            Bump::inner_layout(capacity, align).map_err(|_| NewBumpError::InvalidLayout)?;
+        let inner = PoolInner {
+            capacity,
+            align,
+            layout,
+            metadata_offset,
+            free: RefCell::new(Vec::with_capacity(reserved)),
+        };
+        for _ in 0..reserved {
+            let chunk = inner.alloc_chunk()?;
+            inner.free.borrow_mut().push(chunk);
+        }
+        Ok(Self {
+            inner: Rc::new(inner),
+        })
+    }
+
+    /// Creates a new pool of chunks.
+    ///
+    /// See [`ChunkPool::try_new`].
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(capacity: usize, align: usize, reserved: usize) -> Self {
+        Self::try_new(capacity, align, reserved).unwrap_or_else(|err| err.panic())
+    }
+
+    /// The number of chunks currently available in the pool
+    pub fn available(&self) -> usize {
+        self.inner.free.borrow().len()
+    }
+
+    /// Try to create a new bump drawing its memory from the pool.
+    ///
+    /// A fresh chunk is allocated if the pool is empty, which will
+    /// join the pool once the bump is deallocated.
+    pub fn try_bump(&self) -> Result<Bump, NewBumpError> {
+        let chunk = match self.inner.free.borrow_mut().pop() {
+            Some(chunk) => chunk,
+            None => self.inner.alloc_chunk()?,
+        };
+        // Safety: chunks of the pool are allocated with its layout
+        Ok(unsafe {
+            Bump::init_chunk(
+                chunk,
+                self.inner.layout,
+                self.inner.metadata_offset,
+                Some(self.inner.clone()),
+            )
+        })
+    }
+
+    /// Try to create a new paving whose bumps are drawn from the pool.
+    pub fn try_paving(&self) -> Result<Paving, NewBumpError> {
+        Paving::try_from_pool(self.clone())
+    }
+
+    /// Creates a new paving whose bumps are drawn from the pool.
+    ///
+    /// See [`ChunkPool::try_paving`].
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn paving(&self) -> Paving {
+        self.try_paving().unwrap_or_else(|err| err.panic())
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.inner.capacity
+    }
+
+    pub(crate) fn align(&self) -> usize {
+        self.inner.align
+    }
+}
+
+impl PoolInner {
+    fn alloc_chunk(&self) -> Result<NonNull<u8>, NewBumpError> {
+        // # Safety:
+        // layout has a non zero size
+        let chunk = unsafe { alloc(self.layout) };
+        NonNull::new(chunk).ok_or(NewBumpError::OutOfMemory)
+    }
+
+    // Hands a chunk whose bump was deallocated back to the pool
+    pub(crate) fn recycle(&self, chunk: NonNull<u8>) {
+        self.free.borrow_mut().push(chunk);
+    }
+}
+
+impl Drop for PoolInner {
+    fn drop(&mut self) {
+        for chunk in self.free.get_mut().drain(..) {
+            // Safety: chunks of the pool are allocated with its layout,
+            // and not referenced by any bump
+            unsafe { dealloc(chunk.as_ptr(), self.layout) };
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+
+use crate::{BumpBytes, NewBumpError, Paving};
+
+/// A handle to a key interned in a [`PrefixInterner`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PrefixKey {
+    node: usize,
+}
+
+// A node of the radix tree, reached from its parent through its label
+struct Node {
+    label: BumpBytes,
+    parent: usize,
+    children: BTreeMap<u8, usize>,
+    interned: bool,
+}
+
+/// An interner of byte strings, storing them in a radix tree whose
+/// labels live in a paving.
+///
+/// Keys sharing a prefix share the bytes of that prefix, which suits
+/// large sets of similar keys such as file paths or URLs.
+pub struct PrefixInterner {
+    paving: Paving,
+    nodes: Vec<Node>,
+    len: usize,
+}
+
+impl PrefixInterner {
+    /// Try to create a new interner, whose backing bumps will have
+    /// the corresponding capacity.
+    ///
+    /// See [`Bump::try_new`](`crate::Bump::try_new`).
+    pub fn try_new(capacity: usize) -> Result<Self, NewBumpError> {
+        let paving = Paving::try_new(capacity, 1)?;
+        let label = paving
+            .try_alloc_bump_bytes(&[])
+            .ok_or(NewBumpError::InvalidLayout)?;
+        let root = Node {
+            label,
+            parent: 0,
+            children: BTreeMap::new(),
+            interned: false,
+        };
+        Ok(Self {
+            paving,
+            nodes: vec![root],
+            len: 0,
+        })
+    }
+
+    /// Creates a new interner, whose backing bumps will have
+    /// the corresponding capacity.
+    ///
+    /// See [`Bump::new`](`crate::Bump::new`).
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(capacity: usize) -> Self {
+        Self::try_new(capacity).unwrap_or_else(|err| err.panic())
+    }
+
+    /// Try to intern a key, returning the existing handle if it was
+    /// already interned.
+    ///
+    /// Only the bytes not shared with a previously interned key are
+    /// copied into the paving. Fails if they cannot be allocated.
+    pub fn try_intern(&mut self, key: &[u8]) -> Option<PrefixKey> {
+        let mut node = 0;
+        let mut rest = key;
+        loop {
+            let Some(&first) = rest.first() else {
+                if let Some(Node { interned, .. }) = self.nodes.get_mut(node) {
+                    if !*interned {
+                        *interned = true;
+                        self.len += 1;
+                    }
+                }
+                return Some(PrefixKey { node });
+            };
+            let Some(&child) = self.nodes.get(node)?.children.get(&first) else {
+                let label = self.paving.try_alloc_bump_bytes(rest)?;
+                let leaf = self.push_node(label, node);
+                self.nodes.get_mut(leaf)?.interned = true;
+                self.len += 1;
+                return Some(PrefixKey { node: leaf });
+            };
+            let label = &self.nodes.get(child)?.label;
+            let common = label.iter().zip(rest).take_while(|(a, b)| a == b).count();
+            node = if common < label.len() {
+                self.split(child, common)?
+            } else {
+                child
+            };
+            rest = rest.get(common..)?;
+        }
+    }
+
+    /// Returns the handle of `key`, if it was interned
+    pub fn get(&self, key: &[u8]) -> Option<PrefixKey> {
+        let mut node = 0;
+        let mut rest = key;
+        while let Some(first) = rest.first() {
+            node = *self.nodes.get(node)?.children.get(first)?;
+            rest = rest.strip_prefix(&*self.nodes.get(node)?.label)?;
+        }
+        self.nodes.get(node)?.interned.then_some(PrefixKey { node })
+    }
+
+    /// Returns the bytes of an interned key, or None if the handle does
+    /// not come from this interner.
+    pub fn resolve(&self, key: PrefixKey) -> Option<Vec<u8>> {
+        if !self.nodes.get(key.node)?.interned {
+            return None;
+        }
+        let mut labels = Vec::new();
+        let mut node = key.node;
+        while node != 0 {
+            let current = self.nodes.get(node)?;
+            labels.push(&current.label);
+            node = current.parent;
+        }
+        Some(
+            labels
+                .into_iter()
+                .rev()
+                .flat_map(|label| label.iter().copied())
+                .collect(),
+        )
+    }
+
+    /// Returns the number of distinct keys interned
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no key was interned
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bytes of keys stored in the paving,
+    /// which is lower than their total length when they share prefixes.
+    pub fn stored_bytes(&self) -> usize {
+        self.nodes.iter().map(|node| node.label.len()).sum()
+    }
+
+    fn push_node(&mut self, label: BumpBytes, parent: usize) -> usize {
+        let index = self.nodes.len();
+        if let (Some(&first), Some(parent)) = (label.first(), self.nodes.get_mut(parent)) {
+            parent.children.insert(first, index);
+        }
+        self.nodes.push(Node {
+            label,
+            parent,
+            children: BTreeMap::new(),
+            interned: false,
+        });
+        index
+    }
+
+    // Inserts a node between `node` and its parent, holding the first
+    // `at` bytes of its label, and returns it.
+    // Handles stay valid as no node moves.
+    fn split(&mut self, node: usize, at: usize) -> Option<usize> {
+        let current = self.nodes.get(node)?;
+        let (prefix, suffix) = (current.label.get(..at)?, current.label.get(at..)?);
+        let middle = self.push_node(prefix, current.parent);
+        let current = self.nodes.get_mut(node)?;
+        current.parent = middle;
+        let first = *suffix.first()?;
+        current.label = suffix;
+        self.nodes.get_mut(middle)?.children.insert(first, node);
+        Some(middle)
+    }
+}
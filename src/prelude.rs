@@ -0,0 +1,22 @@
+//! The commonly used items of the crate, to be glob imported.
+//!
+//! ```
+//! use rc_bump::prelude::*;
+//!
+//! let paving = Paving::new(1024, 8);
+//! let member: RcBumpMember<u64> = paving.try_alloc_rc(1).ok().unwrap();
+//! assert!(same_bump(&member, &paving.try_alloc(2).ok().unwrap()));
+//! ```
+
+pub use crate::{
+    alloc_dyn, borrow_family, bump_format, same_bump, ArcBumpMember, BorrowFamily, Bump, BumpBytes,
+    BumpMember, BumpStr, ChunkPool, Columnar, ColumnarPaving, HashConsPaving, IntrusiveCount,
+    IntrusiveCounter, IntrusiveRcBumpMember, MappedBumpMember, MappedRcBumpMember, Member,
+    MixedPaving, NewBumpError, Paving, RcBumpMember, SegmentedPaving, SelfRefMember, SyncBump,
+    SyncPaving, Trace, WeakBumpMember,
+};
+
+pub use crate::collections::{BumpString, BumpVec};
+
+#[cfg(feature = "zeroize")]
+pub use crate::Sensitive;
@@ -0,0 +1,160 @@
+use std::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use crate::{member::Sealed, metadata::Metadata, BumpMember, RcBumpMember};
+
+// Implemented by every type, so that a member can be turned into a
+// `dyn Erased` one which still knows how to drop its value.
+pub(crate) trait Erased {}
+
+impl<T> Erased for T {}
+
+/// A member projected onto a part of its value, like [`std::cell::RefMut::map`].
+///
+/// The whole value and its bump are kept alive, and the value
+/// is dropped along with this member.
+pub struct MappedBumpMember<'a, U: ?Sized> {
+    data: NonNull<U>,
+    // Keeps the whole value alive
+    owner: BumpMember<dyn Erased + 'a>,
+    // U can be written to, so it must be invariant
+    _marker: PhantomData<*mut U>,
+}
+
+impl<T> BumpMember<T> {
+    /// Projects the member onto a part of its value, for instance one of its
+    /// fields.
+    ///
+    /// This is an associated function, so as not to shadow methods of `T`.
+    pub fn map<'a, U: ?Sized>(
+        mut this: Self,
+        f: impl FnOnce(&mut T) -> &mut U,
+    ) -> MappedBumpMember<'a, U>
+    where
+        T: 'a,
+    {
+        let data = NonNull::from(f(&mut this));
+        MappedBumpMember {
+            data,
+            owner: this.erase(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, U: ?Sized> MappedBumpMember<'a, U> {
+    /// Projects the member further, see [`BumpMember::map`].
+    pub fn map<V: ?Sized>(
+        mut this: Self,
+        f: impl FnOnce(&mut U) -> &mut V,
+    ) -> MappedBumpMember<'a, V> {
+        let data = NonNull::from(f(&mut this));
+        MappedBumpMember {
+            data,
+            owner: this.owner,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<U: ?Sized> Deref for MappedBumpMember<'_, U> {
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        // Safety: data borrows from the value kept alive by owner
+        unsafe { self.data.as_ref() }
+    }
+}
+
+impl<U: ?Sized> DerefMut for MappedBumpMember<'_, U> {
+    fn deref_mut(&mut self) -> &mut U {
+        // Safety:
+        // data borrows from the value kept alive by owner,
+        // which cannot be reached otherwise
+        unsafe { self.data.as_mut() }
+    }
+}
+
+/// A shared member projected onto a part of its value, like
+/// [`std::cell::Ref::map`].
+///
+/// The whole value and its bump are kept alive as long as
+/// a clone of this member or of the original one lives.
+pub struct MappedRcBumpMember<'a, U: ?Sized> {
+    data: NonNull<U>,
+    // Keeps the whole value alive
+    owner: RcBumpMember<dyn Erased + 'a>,
+}
+
+impl<T> RcBumpMember<T> {
+    /// Projects the member onto a part of its value, for instance one of its
+    /// fields.
+    ///
+    /// This is an associated function, so as not to shadow methods of `T`.
+    pub fn map<'a, U: ?Sized>(this: Self, f: impl FnOnce(&T) -> &U) -> MappedRcBumpMember<'a, U>
+    where
+        T: 'a,
+    {
+        let data = NonNull::from(f(&this));
+        MappedRcBumpMember {
+            data,
+            owner: this.erase(),
+        }
+    }
+}
+
+impl<'a, U: ?Sized> MappedRcBumpMember<'a, U> {
+    /// Projects the member further, see [`RcBumpMember::map`].
+    pub fn map<V: ?Sized>(this: Self, f: impl FnOnce(&U) -> &V) -> MappedRcBumpMember<'a, V> {
+        let data = NonNull::from(f(&this));
+        MappedRcBumpMember {
+            data,
+            owner: this.owner,
+        }
+    }
+}
+
+impl<U: ?Sized> Deref for MappedRcBumpMember<'_, U> {
+    type Target = U;
+
+    fn deref(&self) -> &U {
+        // Safety: data borrows from the value kept alive by owner
+        unsafe { self.data.as_ref() }
+    }
+}
+
+impl<U: ?Sized> Clone for MappedRcBumpMember<'_, U> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data,
+            owner: self.owner.clone(),
+        }
+    }
+}
+
+macro_rules! mapped_traits {
+    ($($mapped:ident),*) => {$(
+        impl<U: ?Sized + fmt::Debug> fmt::Debug for $mapped<'_, U> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                U::fmt(self, f)
+            }
+        }
+
+        impl<U: ?Sized> Sealed for $mapped<'_, U> {
+            fn metadata(&self) -> NonNull<Metadata> {
+                self.owner.metadata()
+            }
+
+            #[cfg(feature = "provenance")]
+            fn data(&self) -> NonNull<u8> {
+                self.data.cast()
+            }
+        }
+    )*};
+}
+
+mapped_traits!(MappedBumpMember, MappedRcBumpMember);
@@ -0,0 +1,39 @@
+use std::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::Bump;
+
+static NEXT_CHUNK_ID: AtomicU64 = AtomicU64::new(0);
+
+// A process-wide unique identifier for a new chunk
+pub(crate) fn next_chunk_id() -> u64 {
+    NEXT_CHUNK_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Where a member lives in the arena, as returned by
+/// [`Member::provenance`](crate::Member::provenance).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Provenance {
+    /// The identifier of the chunk, unique in the process
+    pub chunk: u64,
+    /// The offset in bytes of the member from the beginning of the chunk
+    pub offset: usize,
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "chunk {} at offset {:#x}", self.chunk, self.offset)
+    }
+}
+
+impl Bump {
+    /// The identifier of the chunk of this bump, unique in the process.
+    ///
+    /// See [`Provenance`].
+    pub fn chunk_id(&self) -> u64 {
+        // Safety: metadata is valid as long as self lives
+        unsafe { self.metadata().as_ref() }.id
+    }
+}
@@ -0,0 +1,41 @@
+use std::{cell::RefCell, collections::BTreeMap, ptr::NonNull};
+
+use crate::metadata::Metadata;
+
+thread_local! {
+    // The chunks holding values turned into raw pointers on this thread, by
+    // the address of their first byte, with the number of such values, so
+    // that the chunk of a value can be found from a pointer to it.
+    // Chunks are only registered while they hold such values, which keep
+    // them alive.
+    static CHUNKS: RefCell<BTreeMap<usize, (NonNull<Metadata>, usize)>> =
+        const { RefCell::new(BTreeMap::new()) };
+}
+
+// Registers the chunk of a value turned into a raw pointer
+pub(crate) fn register(metadata: NonNull<Metadata>) {
+    // Safety: the chunk is kept alive by the value
+    let beg = unsafe { metadata.as_ref() }.beg.as_ptr() as usize;
+    CHUNKS.with_borrow_mut(|chunks| chunks.entry(beg).or_insert((metadata, 0)).1 += 1);
+}
+
+// Finds the chunk of a value turned into a raw pointer from a pointer
+// to it, unregistering the value.
+// The metadata ends the capacity of the chunk, which may end
+// with zero-sized values.
+pub(crate) fn take_chunk_of(ptr: *const u8) -> Option<NonNull<Metadata>> {
+    let address = ptr as usize;
+    CHUNKS.with_borrow_mut(|chunks| {
+        let mut entry = chunks.range_mut(..=address).next_back()?;
+        let (&beg, (metadata, values)) = &mut entry;
+        let metadata = *metadata;
+        if address > metadata.as_ptr() as usize {
+            return None;
+        }
+        *values -= 1;
+        if *values == 0 {
+            chunks.remove(&beg);
+        }
+        Some(metadata)
+    })
+}
@@ -0,0 +1,108 @@
+use std::{alloc::Layout, fmt, ptr::NonNull};
+
+use crate::{member::Sealed, metadata::Metadata, Bump, Paving};
+
+/// Room allocated in a [`Bump`] for a layout rather than a type, keeping
+/// the bump alive.
+///
+/// Nothing is dropped along with it: it is up to the user to drop what
+/// was written there, before the room itself is dropped. This is meant to
+/// build custom containers or headers on top of a bump.
+pub struct RawAlloc {
+    metadata: NonNull<Metadata>,
+    ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+impl RawAlloc {
+    /// Returns a pointer to the room, valid for reads and writes of
+    /// `layout().size()` bytes as long as `self` lives.
+    pub fn as_ptr(&self) -> NonNull<u8> {
+        self.ptr
+    }
+
+    /// The layout the room was allocated for
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Try to grow or shrink the room to `new_size` bytes, in place.
+    ///
+    /// Fails if it is not the last allocation of its bump, or if there is
+    /// not enough memory left
+    pub fn try_realloc(&mut self, new_size: usize) -> bool {
+        let Ok(layout) = Layout::from_size_align(new_size, self.layout.align()) else {
+            return false;
+        };
+        // Safety: metadata is kept alive by self
+        let metadata = unsafe { self.metadata.as_ref() };
+        // Safety: the room is owned by self, which will not use it past new_size
+        if !unsafe { metadata.try_resize(self.ptr, self.layout.size(), new_size) } {
+            return false;
+        }
+        self.layout = layout;
+        true
+    }
+}
+
+impl Bump {
+    /// Try to allocate room for the layout, to be used as raw memory.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_layout(&self, layout: Layout) -> Option<RawAlloc> {
+        let ptr = self.claim(layout)?;
+        Some(RawAlloc {
+            metadata: self.metadata(),
+            ptr,
+            layout,
+        })
+    }
+}
+
+impl Paving {
+    /// Try to allocate room for the layout in the paving, to be used as
+    /// raw memory.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the layout
+    pub fn try_alloc_layout(&self, layout: Layout) -> Option<RawAlloc> {
+        self.try_alloc_inner(layout, (), |bump, ()| {
+            bump.try_alloc_layout(layout).ok_or(())
+        })
+        .ok()
+    }
+}
+
+impl fmt::Debug for RawAlloc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawAlloc")
+            .field("ptr", &self.ptr)
+            .field("layout", &self.layout)
+            .finish()
+    }
+}
+
+impl Sealed for RawAlloc {
+    fn metadata(&self) -> NonNull<Metadata> {
+        self.metadata
+    }
+
+    #[cfg(feature = "provenance")]
+    fn data(&self) -> NonNull<u8> {
+        self.ptr
+    }
+}
+
+impl Drop for RawAlloc {
+    fn drop(&mut self) {
+        // Safety: the room is not used anymore
+        #[cfg(feature = "zeroize")]
+        unsafe {
+            crate::sensitive::wipe_bytes(self.metadata, self.ptr, self.layout.size())
+        };
+        // Safety:
+        // No other reference to metadata currently exists
+        // (only pointers)
+        unsafe { Metadata::decrement_and_drop(self.metadata) };
+    }
+}
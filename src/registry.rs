@@ -0,0 +1,91 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex, PoisonError, Weak,
+};
+
+use crate::{chunks::ChunkList, Paving};
+
+// The pavings registered with a name, see Paving::with_name
+static REGISTRY: Mutex<Vec<Weak<Registration>>> = Mutex::new(Vec::new());
+
+/// The memory held by a named paving, as returned by [`report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArenaReport {
+    /// The name the paving was registered with
+    pub name: String,
+    /// The number of live chunks of the paving
+    pub chunks: usize,
+    /// The number of bytes allocated for these chunks
+    pub bytes: usize,
+}
+
+// The counters of a registered paving, updated as its chunks
+// are created and deallocated
+pub(crate) struct Registration {
+    name: String,
+    chunks: AtomicUsize,
+    bytes: AtomicUsize,
+}
+
+impl Registration {
+    pub(crate) fn add(&self, bytes: usize) {
+        self.chunks.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub(crate) fn remove(&self, bytes: usize) {
+        self.chunks.fetch_sub(1, Ordering::Relaxed);
+        self.bytes.fetch_sub(bytes, Ordering::Relaxed);
+    }
+
+    fn report(&self) -> ArenaReport {
+        ArenaReport {
+            name: self.name.clone(),
+            chunks: self.chunks.load(Ordering::Relaxed),
+            bytes: self.bytes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+impl ChunkList {
+    fn register(&self, name: String) {
+        let registration = Arc::new(Registration {
+            name,
+            chunks: AtomicUsize::new(0),
+            bytes: AtomicUsize::new(0),
+        });
+        for metadata in self.iter() {
+            // Safety: linked metadata are alive
+            registration.add(unsafe { metadata.as_ref() }.layout.size());
+        }
+        let mut registry = REGISTRY.lock().unwrap_or_else(PoisonError::into_inner);
+        registry.retain(|entry| entry.strong_count() > 0);
+        registry.push(Arc::downgrade(&registration));
+        drop(registry);
+        self.set_registration(Some(registration));
+    }
+}
+
+impl Paving {
+    /// Registers the paving under the given name, so that the memory
+    /// it holds appears in [`report`].
+    ///
+    /// The paving stays registered as long as some of its chunks are alive.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.chunk_list().register(name.into());
+        self
+    }
+}
+
+/// Summarizes the memory held by every live named paving.
+///
+/// See [`Paving::with_name`].
+pub fn report() -> Vec<ArenaReport> {
+    let mut registry = REGISTRY.lock().unwrap_or_else(PoisonError::into_inner);
+    registry.retain(|entry| entry.strong_count() > 0);
+    registry
+        .iter()
+        .filter_map(Weak::upgrade)
+        .map(|registration| registration.report())
+        .collect()
+}
@@ -0,0 +1,120 @@
+use std::{fmt, marker::PhantomData, mem::size_of};
+
+use crate::{ChunkInfo, Pod, PortablePod};
+
+/// A pointer to a value of the same chunk, stored as an offset from
+/// its own address rather than as an address.
+///
+/// It stays valid when the whole chunk is moved, for instance when it is
+/// copied to or mapped from a file, as long as it and its target are moved
+/// together. It does not keep its target alive.
+///
+/// As an offset of 0 stands for no target, a `RelMember` cannot point
+/// to a value starting at its own address.
+///
+/// Being [`Pod`], it can be stored in a [`PodPaving`](crate::PodPaving),
+/// whose dumps keep the values of a chunk in place.
+#[repr(transparent)]
+pub struct RelMember<T> {
+    offset: isize,
+    _marker: PhantomData<*const T>,
+}
+
+impl<T> RelMember<T> {
+    /// Creates a pointer to nothing, to be set once in place.
+    pub const fn null() -> Self {
+        RelMember {
+            offset: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Whether the pointer has no target
+    pub fn is_null(&self) -> bool {
+        self.offset == 0
+    }
+
+    /// Points to `target`, from the current address of this member, if both
+    /// lie in `chunk`, as returned by [`Member::chunk`](crate::Member::chunk).
+    ///
+    /// Returns false, leaving the pointer unchanged, otherwise.
+    pub fn try_set(&mut self, target: &T, chunk: &ChunkInfo) -> bool {
+        let this = self as *const Self as usize;
+        let target = target as *const T as usize;
+        let in_chunk = |start: usize, size: usize| {
+            let beg = chunk.base as usize;
+            start >= beg && start.saturating_add(size) <= beg + chunk.capacity
+        };
+        if !in_chunk(this, size_of::<Self>()) || !in_chunk(target, size_of::<T>()) {
+            return false;
+        }
+        self.offset = (target as isize).wrapping_sub(this as isize);
+        true
+    }
+
+    /// Removes the target of the pointer
+    pub fn clear(&mut self) {
+        self.offset = 0;
+    }
+
+    /// Returns the address of the target, computed from the current
+    /// address of this member, or None if it has no target.
+    pub fn as_ptr(&self) -> Option<*const T> {
+        if self.is_null() {
+            return None;
+        }
+        let this = self as *const Self as *const u8;
+        Some(this.wrapping_offset(self.offset).cast())
+    }
+
+    /// Returns the target, or None if the pointer has no target.
+    ///
+    /// # Safety
+    ///
+    /// The target must be alive, and must have moved along with this member
+    /// since the pointer was set.
+    pub unsafe fn get(&self) -> Option<&T> {
+        self.as_ptr().map(|ptr| &*ptr)
+    }
+
+    /// Returns the target, or None if the pointer has no target.
+    ///
+    /// # Safety
+    ///
+    /// As for [`RelMember::get`], and no other reference to the target
+    /// must be alive.
+    pub unsafe fn get_mut(&mut self) -> Option<&mut T> {
+        self.as_ptr().map(|ptr| &mut *ptr.cast_mut())
+    }
+}
+
+impl<T> Clone for RelMember<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RelMember<T> {}
+
+// Safety:
+// it is a plain isize, whose value is only used as an address once
+// added to the address of the member
+unsafe impl<T: 'static> Pod for RelMember<T> {}
+
+impl<T: 'static> PortablePod for RelMember<T> {
+    fn swap_bytes(&mut self) {
+        self.offset = self.offset.swap_bytes();
+    }
+}
+
+impl<T> Default for RelMember<T> {
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl<T> fmt::Debug for RelMember<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RelMember").field(&self.offset).finish()
+    }
+}
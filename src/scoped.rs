@@ -0,0 +1,209 @@
+use std::{
+    alloc::Layout,
+    cell::Cell,
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::{self, drop_in_place, NonNull},
+};
+
+use crate::{member::Sealed, metadata::Metadata, Bump, Paving};
+
+/// Keeps a bump alive for a scope, so that the members allocated through it
+/// do not need to update the count of the bump.
+///
+/// See [`Bump::guard`].
+pub struct BumpGuard {
+    bump: Bump,
+}
+
+/// Keeps alive every bump a paving allocates in during a scope, so that the
+/// members allocated through it do not need to update the count of their
+/// bump.
+///
+/// See [`Paving::guard`].
+pub struct PavingGuard<'p> {
+    paving: &'p Paving,
+    // The bumps used by the guard, the last one being the most recent
+    bumps: Cell<Vec<Bump>>,
+    last: Cell<Option<NonNull<Metadata>>>,
+}
+
+/// A member allocated through a [`BumpGuard`] or a [`PavingGuard`], which
+/// cannot outlive it.
+///
+/// It owns its value like a [`BumpMember`](crate::BumpMember), but is cheaper
+/// to create and drop.
+pub struct ScopedMember<'g, T: ?Sized> {
+    metadata: NonNull<Metadata>,
+    data: NonNull<T>,
+    _marker: PhantomData<(&'g (), T)>,
+}
+
+impl Bump {
+    /// Returns a guard keeping this bump alive, to allocate many short lived
+    /// members cheaply.
+    pub fn guard(&self) -> BumpGuard {
+        BumpGuard { bump: self.clone() }
+    }
+}
+
+impl Paving {
+    /// Returns a guard keeping alive the bumps of this paving it allocates
+    /// in, to allocate many short lived members cheaply.
+    pub fn guard(&self) -> PavingGuard<'_> {
+        PavingGuard {
+            paving: self,
+            bumps: Cell::new(Vec::new()),
+            last: Cell::new(None),
+        }
+    }
+}
+
+impl BumpGuard {
+    /// Try to allocate an object in the bump.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc<T>(&self, value: T) -> Result<ScopedMember<'_, T>, T> {
+        write_value(self.claim(Layout::new::<T>()), value)
+    }
+
+    /// Try to copy a slice into the bump.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_slice_copy<T: Copy>(&self, src: &[T]) -> Option<ScopedMember<'_, [T]>> {
+        copy_slice(self.claim(Layout::for_value(src))?, src)
+    }
+
+    fn claim(&self, layout: Layout) -> Option<(NonNull<Metadata>, NonNull<u8>)> {
+        let start = self.bump.claim_uncounted(layout)?;
+        Some((self.bump.metadata(), start))
+    }
+}
+
+impl PavingGuard<'_> {
+    /// Try to allocate an object in the paving.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the object
+    pub fn try_alloc<T>(&self, value: T) -> Result<ScopedMember<'_, T>, T> {
+        write_value(self.claim(Layout::new::<T>()), value)
+    }
+
+    /// Try to copy a slice into the paving.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the slice
+    pub fn try_alloc_slice_copy<T: Copy>(&self, src: &[T]) -> Option<ScopedMember<'_, [T]>> {
+        copy_slice(self.claim(Layout::for_value(src))?, src)
+    }
+
+    fn claim(&self, layout: Layout) -> Option<(NonNull<Metadata>, NonNull<u8>)> {
+        self.paving
+            .try_alloc_inner(layout, (), |bump, ()| {
+                let start = bump.claim_uncounted(layout).ok_or(())?;
+                self.keep(bump);
+                Ok((bump.metadata(), start))
+            })
+            .ok()
+    }
+
+    // Keeps the bump alive until the guard is dropped
+    fn keep(&self, bump: &Bump) {
+        if self.last.get() == Some(bump.metadata()) {
+            return;
+        }
+        let mut bumps = self.bumps.take();
+        bumps.push(bump.clone());
+        self.bumps.set(bumps);
+        self.last.set(Some(bump.metadata()));
+    }
+}
+
+fn write_value<'g, T>(
+    claimed: Option<(NonNull<Metadata>, NonNull<u8>)>,
+    value: T,
+) -> Result<ScopedMember<'g, T>, T> {
+    let Some((metadata, start)) = claimed else {
+        return Err(value);
+    };
+    let data = start.cast::<T>();
+    // Safety: data was just claimed for a T, so it is valid for writes
+    unsafe { data.as_ptr().write(value) };
+    Ok(ScopedMember {
+        metadata,
+        data,
+        _marker: PhantomData,
+    })
+}
+
+fn copy_slice<'g, T: Copy>(
+    (metadata, start): (NonNull<Metadata>, NonNull<u8>),
+    src: &[T],
+) -> Option<ScopedMember<'g, [T]>> {
+    let start = start.cast::<T>();
+    // Safety: start was just claimed for src, so it cannot overlap with it
+    unsafe { ptr::copy_nonoverlapping(src.as_ptr(), start.as_ptr(), src.len()) };
+    Some(ScopedMember {
+        metadata,
+        data: NonNull::slice_from_raw_parts(start, src.len()),
+        _marker: PhantomData,
+    })
+}
+
+impl<'g, T: ?Sized> ScopedMember<'g, T> {
+    /// Consumes the member, returning a reference to its value for as long
+    /// as the guard lives. The value is never dropped.
+    pub fn leak(this: Self) -> &'g mut T {
+        let this = std::mem::ManuallyDrop::new(this);
+        // Safety: the value is kept alive by the guard, and was only owned by this
+        unsafe { &mut *this.data.as_ptr() }
+    }
+}
+
+impl<T: ?Sized> Deref for ScopedMember<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // Safety: the value is kept alive by the guard
+        unsafe { self.data.as_ref() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for ScopedMember<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // Safety: the value is kept alive by the guard, and only owned by self
+        unsafe { self.data.as_mut() }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for ScopedMember<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt(self, f)
+    }
+}
+
+impl<T: ?Sized> Sealed for ScopedMember<'_, T> {
+    fn metadata(&self) -> NonNull<Metadata> {
+        self.metadata
+    }
+
+    #[cfg(feature = "provenance")]
+    fn data(&self) -> NonNull<u8> {
+        self.data.cast()
+    }
+}
+
+impl<T: ?Sized> Drop for ScopedMember<'_, T> {
+    fn drop(&mut self) {
+        #[cfg(feature = "zeroize")]
+        let size = std::mem::size_of_val::<T>(self);
+        // Safety: the value is only owned by self, and dropped once
+        unsafe { drop_in_place(self.data.as_ptr()) };
+        // Safety: the value was dropped
+        #[cfg(feature = "zeroize")]
+        unsafe {
+            crate::sensitive::wipe_bytes(self.metadata, self.data.cast(), size)
+        };
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{BumpMember, NewBumpError, Paving, RcBumpMember};
+
+/// A paving routing objects into two separate streams of bumps,
+/// so that frequently accessed objects are not interleaved with
+/// rarely accessed ones.
+pub struct SegmentedPaving {
+    hot: Paving,
+    cold: Paving,
+}
+
+impl SegmentedPaving {
+    /// Try to create a new segmented paving, whose hot and cold backing bumps
+    /// will have the corresponding capacities and the same align.
+    ///
+    /// See [`Bump::try_new`](`crate::Bump::try_new`).
+    pub fn try_new(
+        hot_capacity: usize,
+        cold_capacity: usize,
+        align: usize,
+    ) -> Result<Self, NewBumpError> {
+        Ok(Self {
+            hot: Paving::try_new(hot_capacity, align)?,
+            cold: Paving::try_new(cold_capacity, align)?,
+        })
+    }
+
+    /// Creates a new segmented paving, whose hot and cold backing bumps will
+    /// have the corresponding capacities and the same align.
+    ///
+    /// See [`Bump::new`](`crate::Bump::new`).
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(hot_capacity: usize, cold_capacity: usize, align: usize) -> Self {
+        Self::try_new(hot_capacity, cold_capacity, align).unwrap_or_else(|err| err.panic())
+    }
+
+    /// The paving holding frequently accessed objects
+    pub fn hot(&self) -> &Paving {
+        &self.hot
+    }
+
+    /// The paving holding rarely accessed objects
+    pub fn cold(&self) -> &Paving {
+        &self.cold
+    }
+
+    /// Try to allocate a frequently accessed object.
+    ///
+    /// See [`Paving::try_alloc`].
+    pub fn try_alloc_hot<T>(&self, value: T) -> Result<BumpMember<T>, T> {
+        self.hot.try_alloc(value)
+    }
+
+    /// Try to allocate a rarely accessed object.
+    ///
+    /// See [`Paving::try_alloc`].
+    pub fn try_alloc_cold<T>(&self, value: T) -> Result<BumpMember<T>, T> {
+        self.cold.try_alloc(value)
+    }
+
+    /// Try to allocate a frequently accessed object with shared ownership.
+    ///
+    /// See [`Paving::try_alloc_rc`].
+    pub fn try_alloc_rc_hot<T>(&self, value: T) -> Result<RcBumpMember<T>, T> {
+        self.hot.try_alloc_rc(value)
+    }
+
+    /// Try to allocate a rarely accessed object with shared ownership.
+    ///
+    /// See [`Paving::try_alloc_rc`].
+    pub fn try_alloc_rc_cold<T>(&self, value: T) -> Result<RcBumpMember<T>, T> {
+        self.cold.try_alloc_rc(value)
+    }
+}
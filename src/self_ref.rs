@@ -0,0 +1,161 @@
+use std::{alloc::Layout, mem::ManuallyDrop};
+
+use crate::{Bump, BumpMember, Paving};
+
+/// A type-level function giving, for any lifetime `'a`, the type of
+/// a value borrowing from an owner for `'a`.
+///
+/// It is usually implemented through [`borrow_family!`](crate::borrow_family).
+pub trait BorrowFamily {
+    /// The type of the dependent value, borrowing for `'a`
+    type Borrowed<'a>;
+}
+
+/// Declares an uninhabited type implementing [`BorrowFamily`].
+///
+/// ```
+/// rc_bump::borrow_family!(pub Tokens = for<'a> Vec<&'a str>);
+/// ```
+#[macro_export]
+macro_rules! borrow_family {
+    ($(#[$attr:meta])* $vis:vis $name:ident = for<$lt:lifetime> $ty:ty) => {
+        $(#[$attr])*
+        $vis enum $name {}
+
+        impl $crate::BorrowFamily for $name {
+            type Borrowed<$lt> = $ty;
+        }
+    };
+}
+
+/// An owner and a value borrowing from it, both living in the same bump.
+///
+/// The bump guarantees the owner never moves, so the dependent can
+/// safely keep references to it for as long as this member lives.
+pub struct SelfRefMember<O, F: BorrowFamily> {
+    // Declared first so that it is dropped before the owner it borrows from.
+    // Its lifetime is a lie, it only borrows for as long as owner lives.
+    dependent: BumpMember<F::Borrowed<'static>>,
+    owner: BumpMember<O>,
+}
+
+impl<O, F: BorrowFamily> SelfRefMember<O, F> {
+    // The layout of an owner immediately followed by its dependent
+    fn layout() -> Option<Layout> {
+        let (layout, _) = Layout::new::<O>()
+            .extend(Layout::new::<F::Borrowed<'static>>())
+            .ok()?;
+        Some(layout)
+    }
+
+    fn try_new(
+        owner: BumpMember<O>,
+        dependent: impl for<'a> FnOnce(&'a O) -> F::Borrowed<'a>,
+        alloc: impl FnOnce(
+            F::Borrowed<'static>,
+        ) -> Result<BumpMember<F::Borrowed<'static>>, F::Borrowed<'static>>,
+    ) -> Result<Self, O> {
+        let dependent = {
+            let dependent = ManuallyDrop::new(dependent(&owner));
+            // Safety:
+            // Only the lifetime changes, and the value is never exposed
+            // with a lifetime longer than the one of owner, whose
+            // address is stable.
+            unsafe {
+                (&*dependent as *const F::Borrowed<'_>)
+                    .cast::<F::Borrowed<'static>>()
+                    .read()
+            }
+        };
+        match alloc(dependent) {
+            Ok(dependent) => Ok(SelfRefMember { dependent, owner }),
+            Err(dependent) => {
+                drop(dependent);
+                Err(owner.take())
+            }
+        }
+    }
+
+    /// Returns a reference to the owner
+    pub fn owner(&self) -> &O {
+        &self.owner
+    }
+
+    /// Gives access to the owner and its dependent
+    pub fn with_dependent<'s, R>(
+        &'s self,
+        f: impl for<'a> FnOnce(&'a O, &'a F::Borrowed<'a>) -> R,
+    ) -> R {
+        let dependent: *const F::Borrowed<'static> = &*self.dependent;
+        // Safety:
+        // The dependent only borrows from the owner, which lives at least
+        // as long as self
+        f(&self.owner, unsafe {
+            &*dependent.cast::<F::Borrowed<'s>>()
+        })
+    }
+
+    /// Gives access to the owner and a mutable reference to its dependent
+    pub fn with_dependent_mut<'s, R>(
+        &'s mut self,
+        f: impl for<'a> FnOnce(&'a O, &'a mut F::Borrowed<'a>) -> R,
+    ) -> R {
+        let dependent: *mut F::Borrowed<'static> = &mut *self.dependent;
+        // Safety:
+        // The dependent only borrows from the owner, which lives at least
+        // as long as self, and f cannot smuggle out references living
+        // less than 's
+        f(&self.owner, unsafe {
+            &mut *dependent.cast::<F::Borrowed<'s>>()
+        })
+    }
+
+    /// Drops the dependent and returns the owner
+    pub fn into_owner(self) -> BumpMember<O> {
+        let SelfRefMember { dependent, owner } = self;
+        drop(dependent);
+        owner
+    }
+}
+
+impl Bump {
+    /// Try to allocate an owner, and a dependent built from a reference to it,
+    /// next to each other in the bump.
+    ///
+    /// Fails if there is not enough memory left for both.
+    pub fn try_alloc_self_ref<O, F: BorrowFamily>(
+        &self,
+        owner: O,
+        dependent: impl for<'a> FnOnce(&'a O) -> F::Borrowed<'a>,
+    ) -> Result<SelfRefMember<O, F>, O> {
+        match SelfRefMember::<O, F>::layout() {
+            Some(layout) if self.can_fit(layout).is_some() => (),
+            _ => return Err(owner),
+        }
+        let owner = self.try_alloc(owner)?;
+        SelfRefMember::try_new(owner, dependent, |dependent| self.try_alloc(dependent))
+    }
+}
+
+impl Paving {
+    /// Try to allocate an owner, and a dependent built from a reference to it,
+    /// next to each other in the paving.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// both objects.
+    pub fn try_alloc_self_ref<O, F: BorrowFamily>(
+        &self,
+        owner: O,
+        dependent: impl for<'a> FnOnce(&'a O) -> F::Borrowed<'a>,
+    ) -> Result<SelfRefMember<O, F>, O> {
+        let Some(layout) = SelfRefMember::<O, F>::layout() else {
+            return Err(owner);
+        };
+        let owner =
+            self.try_alloc_inner(layout, owner, |bump, owner| match bump.can_fit(layout) {
+                Some(_) => bump.try_alloc(owner),
+                None => Err(owner),
+            })?;
+        SelfRefMember::try_new(owner, dependent, |dependent| self.try_alloc(dependent))
+    }
+}
@@ -0,0 +1,110 @@
+use std::{
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+    slice,
+};
+
+use zeroize::Zeroize;
+
+use crate::{metadata::Metadata, Bump};
+
+// # Safety
+// - ptr must be valid for writes of len bytes
+// - no reference to these bytes must exist
+unsafe fn wipe(ptr: *mut u8, len: usize) {
+    slice::from_raw_parts_mut(ptr.cast::<MaybeUninit<u8>>(), len).zeroize();
+}
+
+// Wipes the bytes of a value which was just dropped or moved out
+// of its bump, if the bump is sensitive.
+//
+// # Safety
+// - metadata must not be dangling
+// - value must point into the bump, to a value which is not used anymore
+pub(crate) unsafe fn wipe_value<T>(metadata: NonNull<Metadata>, value: NonNull<T>) {
+    wipe_bytes(metadata, value.cast(), std::mem::size_of::<T>());
+}
+
+// Same as wipe_value, for the len bytes starting at ptr
+//
+// # Safety
+// - metadata must not be dangling
+// - the bytes must be in the bump, and not used anymore
+pub(crate) unsafe fn wipe_bytes(metadata: NonNull<Metadata>, ptr: NonNull<u8>, len: usize) {
+    if metadata.as_ref().sensitive.get() {
+        wipe(ptr.as_ptr(), len);
+    }
+}
+
+// Wipes the whole memory of a sensitive bump about to be deallocated
+pub(crate) fn wipe_chunk(metadata: &Metadata) {
+    if metadata.sensitive.get() {
+        let beg = metadata.beg.as_ptr();
+        let len = metadata as *const Metadata as usize - beg as usize;
+        // Safety:
+        // the range is the memory of the bump, which is still
+        // allocated but not referenced anymore
+        unsafe { wipe(beg, len) };
+    }
+}
+
+impl Bump {
+    /// Marks this bump as holding sensitive data.
+    ///
+    /// The bytes of its members are wiped when they are dropped, and the
+    /// whole bump is wiped when it is deallocated.
+    /// The bytes of shared members of types without drop glue are only wiped
+    /// with the bump.
+    pub fn mark_sensitive(&self) {
+        // Safety: metadata is valid as long as self lives
+        unsafe { self.metadata().as_ref() }.sensitive.set(true);
+    }
+
+    /// Returns true if this bump was marked as holding sensitive data.
+    ///
+    /// See [`Bump::mark_sensitive`].
+    pub fn is_sensitive(&self) -> bool {
+        // Safety: metadata is valid as long as self lives
+        unsafe { self.metadata().as_ref() }.sensitive.get()
+    }
+}
+
+/// A value whose bytes are wiped when it is dropped,
+/// wherever it is stored.
+///
+/// This allows marking single allocations of a bump as sensitive.
+pub struct Sensitive<T>(ManuallyDrop<T>);
+
+impl<T> Sensitive<T> {
+    /// Wraps a sensitive value
+    pub fn new(value: T) -> Self {
+        Sensitive(ManuallyDrop::new(value))
+    }
+}
+
+impl<T> Deref for Sensitive<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Sensitive<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> Drop for Sensitive<T> {
+    fn drop(&mut self) {
+        // Safety: the value is never used again
+        unsafe { ManuallyDrop::drop(&mut self.0) };
+        let value: *mut ManuallyDrop<T> = &mut self.0;
+        // Safety:
+        // value is valid for writes, and the value it contained
+        // was dropped
+        unsafe { wipe(value.cast(), std::mem::size_of::<T>()) };
+    }
+}
@@ -0,0 +1,239 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{DeserializeSeed, Error, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    collections::{BumpString, BumpVec},
+    BumpBytes, BumpMember, BumpStr, Paving, RcBumpMember,
+};
+
+impl<T: ?Sized + Serialize> Serialize for BumpMember<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        T::serialize(self, serializer)
+    }
+}
+
+impl<T: ?Sized + Serialize> Serialize for RcBumpMember<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        T::serialize(self, serializer)
+    }
+}
+
+impl Serialize for BumpBytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self)
+    }
+}
+
+impl Serialize for BumpStr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+/// A type which can be deserialized into a paving, allocating its strings,
+/// byte buffers and nested members there rather than on the heap.
+///
+/// It is implemented for every [`Deserialize`] type, as well as for members,
+/// [`BumpBytes`] and [`BumpStr`]. Types holding those can implement it by
+/// deserializing their fields with a [`PavingSeed`].
+pub trait DeserializeIn<'de>: Sized {
+    /// Deserializes a value, allocating what it holds in the paving.
+    fn deserialize_in<D: Deserializer<'de>>(
+        paving: &Paving,
+        deserializer: D,
+    ) -> Result<Self, D::Error>;
+}
+
+/// Deserializes a value of type `T` into a paving, see [`DeserializeIn`].
+///
+/// See [`Paving::seed`].
+pub struct PavingSeed<'p, T> {
+    paving: &'p Paving,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl Paving {
+    /// Returns a seed deserializing a `T` into this paving, for instance a
+    /// [`BumpMember`] or an [`RcBumpMember`], as members cannot be
+    /// deserialized without an arena.
+    ///
+    /// ```
+    /// use rc_bump::{BumpMember, Paving, RcBumpMember};
+    /// use serde::de::DeserializeSeed;
+    ///
+    /// let paving = Paving::new(256, 8);
+    /// let mut json = serde_json::Deserializer::from_str(r#"["a", "b"]"#);
+    /// let seed = paving.seed::<RcBumpMember<[BumpMember<str>]>>();
+    /// let member = seed.deserialize(&mut json).unwrap();
+    /// assert_eq!(*member, ["a", "b"]);
+    /// ```
+    pub fn seed<T>(&self) -> PavingSeed<'_, T> {
+        PavingSeed {
+            paving: self,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: DeserializeIn<'de>> DeserializeSeed<'de> for PavingSeed<'_, T> {
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize_in(self.paving, deserializer)
+    }
+}
+
+const ALLOC_FAILED: &str = "could not allocate the value in the paving";
+
+impl<'de, T: Deserialize<'de>> DeserializeIn<'de> for T {
+    fn deserialize_in<D: Deserializer<'de>>(_: &Paving, deserializer: D) -> Result<T, D::Error> {
+        T::deserialize(deserializer)
+    }
+}
+
+impl<'de, T: DeserializeIn<'de>> DeserializeIn<'de> for BumpMember<T> {
+    fn deserialize_in<D: Deserializer<'de>>(
+        paving: &Paving,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let value = T::deserialize_in(paving, deserializer)?;
+        paving
+            .try_alloc(value)
+            .map_err(|_| D::Error::custom(ALLOC_FAILED))
+    }
+}
+
+impl<'de, T: DeserializeIn<'de>> DeserializeIn<'de> for RcBumpMember<T> {
+    fn deserialize_in<D: Deserializer<'de>>(
+        paving: &Paving,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let value = T::deserialize_in(paving, deserializer)?;
+        paving
+            .try_alloc_rc(value)
+            .map_err(|_| D::Error::custom(ALLOC_FAILED))
+    }
+}
+
+// Copies the visited string into the paving
+struct StrVisitor<'p, V> {
+    paving: &'p Paving,
+    alloc: fn(&Paving, &str) -> Option<V>,
+}
+
+impl<V> Visitor<'_> for StrVisitor<'_, V> {
+    type Value = V;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<V, E> {
+        (self.alloc)(self.paving, v).ok_or_else(|| E::custom(ALLOC_FAILED))
+    }
+}
+
+macro_rules! deserialize_str {
+    ($($ty:ty => $alloc:expr),*) => {$(
+        impl<'de> DeserializeIn<'de> for $ty {
+            fn deserialize_in<D: Deserializer<'de>>(
+                paving: &Paving,
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(StrVisitor {
+                    paving,
+                    alloc: $alloc,
+                })
+            }
+        }
+    )*};
+}
+
+deserialize_str!(
+    BumpMember<str> => Paving::try_alloc_str,
+    RcBumpMember<str> => |paving, s| {
+        BumpString::try_from_str_in(s, paving)?.try_into_member().ok()
+    },
+    BumpStr => Paving::try_alloc_bump_str
+);
+
+// Copies the visited bytes into the paving
+struct BytesVisitor<'p>(&'p Paving);
+
+impl<'de> Visitor<'de> for BytesVisitor<'_> {
+    type Value = BumpBytes;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("bytes")
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<BumpBytes, E> {
+        self.0
+            .try_alloc_bump_bytes(v)
+            .ok_or_else(|| E::custom(ALLOC_FAILED))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<BumpBytes, A::Error> {
+        let mut bytes = BumpVec::new_in(self.0);
+        while let Some(byte) = seq.next_element()? {
+            bytes
+                .try_push(byte)
+                .map_err(|_| A::Error::custom(ALLOC_FAILED))?;
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+impl<'de> DeserializeIn<'de> for BumpBytes {
+    fn deserialize_in<D: Deserializer<'de>>(
+        paving: &Paving,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(BytesVisitor(paving))
+    }
+}
+
+// Collects the visited elements into a slice in the paving
+struct SliceVisitor<'p, T> {
+    paving: &'p Paving,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'de, T: DeserializeIn<'de>> Visitor<'de> for SliceVisitor<'_, T> {
+    type Value = RcBumpMember<[T]>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = BumpVec::new_in(self.paving);
+        if let Some(len) = seq.size_hint() {
+            values.try_reserve(len);
+        }
+        while let Some(value) = seq.next_element_seed(self.paving.seed::<T>())? {
+            values
+                .try_push(value)
+                .map_err(|_| A::Error::custom(ALLOC_FAILED))?;
+        }
+        values
+            .try_freeze()
+            .map_err(|_| A::Error::custom(ALLOC_FAILED))
+    }
+}
+
+impl<'de, T: DeserializeIn<'de>> DeserializeIn<'de> for RcBumpMember<[T]> {
+    fn deserialize_in<D: Deserializer<'de>>(
+        paving: &Paving,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(SliceVisitor {
+            paving,
+            _marker: PhantomData,
+        })
+    }
+}
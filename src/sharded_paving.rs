@@ -0,0 +1,109 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{paving::fits_new_bump, ArcBumpMember, Bump, NewBumpError, SyncBump};
+
+/// A paving handing out one [`Shard`] per thread, each allocating in
+/// bumps of its own, so that threads never contend.
+///
+/// Members are [`ArcBumpMember`]s, which can be collected from every
+/// thread into a single structure afterwards.
+///
+/// With rayon, a shard can be created per worker with `map_init`.
+pub struct ShardedPaving {
+    capacity: usize,
+    align: usize,
+    chunks_created: AtomicUsize,
+}
+
+/// A handle allocating in bumps of its own for a [`ShardedPaving`].
+pub struct Shard<'p> {
+    paving: &'p ShardedPaving,
+    current_bump: SyncBump,
+}
+
+impl ShardedPaving {
+    /// Try to create a new paving, whose shards will be backed by bumps
+    /// created with correponding capacity and align.
+    ///
+    /// No bump is created until a shard is.
+    pub fn try_new(capacity: usize, align: usize) -> Result<Self, NewBumpError> {
+        // Checks the capacity and align once and for all
+        if capacity == 0 {
+            return Err(NewBumpError::NullCapacity);
+        }
+        Bump::inner_layout(capacity, align).map_err(|_| NewBumpError::InvalidLayout)?;
+        Ok(Self {
+            capacity,
+            align,
+            chunks_created: AtomicUsize::new(0),
+        })
+    }
+
+    /// Creates a new paving, whose shards will be backed by bumps
+    /// created with correponding capacity and align.
+    ///
+    /// See [`ShardedPaving::try_new`].
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(capacity: usize, align: usize) -> Self {
+        Self::try_new(capacity, align).unwrap_or_else(|err| err.panic())
+    }
+
+    /// Try to create a new shard, with a bump of its own.
+    pub fn try_shard(&self) -> Result<Shard<'_>, NewBumpError> {
+        Ok(Shard {
+            paving: self,
+            current_bump: self.new_bump()?,
+        })
+    }
+
+    /// Creates a new shard, with a bump of its own.
+    ///
+    /// See [`ShardedPaving::try_shard`].
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn shard(&self) -> Shard<'_> {
+        self.try_shard().unwrap_or_else(|err| err.panic())
+    }
+
+    /// The number of bumps created by all the shards so far
+    pub fn chunks_created(&self) -> usize {
+        self.chunks_created.load(Ordering::Relaxed)
+    }
+
+    fn new_bump(&self) -> Result<SyncBump, NewBumpError> {
+        let bump = SyncBump::try_new(self.capacity, self.align)?;
+        self.chunks_created.fetch_add(1, Ordering::Relaxed);
+        Ok(bump)
+    }
+}
+
+impl Shard<'_> {
+    /// Try to allocate an object with thread-safe shared ownership
+    /// in the bumps of the shard.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the object
+    pub fn try_alloc_arc<T>(&mut self, value: T) -> Result<ArcBumpMember<T>, T> {
+        if !fits_new_bump(
+            ArcBumpMember::<T>::entry_layout(),
+            self.paving.capacity,
+            self.paving.align,
+        ) {
+            return Err(value);
+        }
+        let value = match self.current_bump.try_alloc_arc(value) {
+            Ok(member) => return Ok(member),
+            Err(value) => value,
+        };
+        let Ok(next_bump) = self.paving.new_bump() else {
+            return Err(value);
+        };
+        self.current_bump = next_bump;
+        self.current_bump.try_alloc_arc(value)
+    }
+}
@@ -0,0 +1,80 @@
+use std::{alloc::Layout, cell::Cell, ptr::NonNull};
+
+use crate::{
+    metadata::{Cursor, Metadata},
+    Bump,
+};
+
+impl Bump {
+    /// Try to carve consecutive regions of the given sizes (in bytes)
+    /// out of the free memory of this bump.
+    ///
+    /// Each region is returned as an independent bump, sharing the
+    /// allocation of this one but with its own cursor, so that
+    /// allocations in different regions never interleave. Splitting
+    /// a region is allowed as well.
+    ///
+    /// Fails if there is not enough memory left for all the regions,
+    /// in which case nothing is carved.
+    pub fn split(&self, sizes: &[usize]) -> Option<Vec<Bump>> {
+        if sizes.is_empty() {
+            return Some(Vec::new());
+        }
+        // Offsets of the cursor and of the data of each region
+        let mut layout = Layout::new::<()>();
+        let mut regions = Vec::with_capacity(sizes.len());
+        for &size in sizes {
+            let (with_cursor, cursor_offset) = layout.extend(Layout::new::<Cursor>()).ok()?;
+            let (with_data, data_offset) =
+                with_cursor.extend(Layout::array::<u8>(size).ok()?).ok()?;
+            layout = with_data;
+            regions.push((cursor_offset, data_offset, size));
+        }
+        let start = self.claim(layout)?;
+        let metadata = self.metadata();
+        let mut bumps = Vec::with_capacity(regions.len());
+        for (i, (cursor_offset, data_offset, size)) in regions.into_iter().enumerate() {
+            if i > 0 {
+                // Safety: metadata is valid as long as self lives
+                unsafe { Metadata::increment(metadata) };
+            }
+            // Safety: the offsets come from the layout of the claimed memory
+            let cursor = unsafe { start.as_ptr().add(cursor_offset).cast::<Cursor>() };
+            // Safety: same as above
+            let data = unsafe { start.as_ptr().add(data_offset) };
+            // Safety: same as above, the data ends within the claimed memory
+            let end = unsafe { data.add(size) };
+            // Safety:
+            // - the cursor is within the claimed memory, and aligned
+            // - none of the pointers is null
+            #[allow(clippy::multiple_unsafe_ops_per_block)]
+            unsafe {
+                cursor.write(Cursor {
+                    first_free: Cell::new(NonNull::new_unchecked(data)),
+                    end: NonNull::new_unchecked(end),
+                })
+            };
+            // Safety:
+            // - the cursor was just initialized and lives in the chunk
+            // - the count was incremented for this region
+            #[allow(clippy::multiple_unsafe_ops_per_block)]
+            bumps.push(unsafe { Bump::from_cursor(metadata, NonNull::new_unchecked(cursor)) });
+        }
+        Some(bumps)
+    }
+
+    /// Try to carve all the free memory of this bump into a new
+    /// independent region.
+    ///
+    /// See [`Bump::split`]. This bump cannot allocate anymore afterwards.
+    ///
+    /// Fails if there is not even enough memory left for the region to be
+    /// created.
+    pub fn split_off_remaining(&self) -> Option<Bump> {
+        let (_, after_cursor) = self.can_fit(Layout::new::<Cursor>())?;
+        // Safety: the cursor is valid as long as self lives
+        let end = unsafe { self.cursor().as_ref() }.end;
+        let remaining = end.as_ptr() as usize - after_cursor.as_ptr() as usize;
+        self.split(&[remaining])?.pop()
+    }
+}
@@ -0,0 +1,116 @@
+use std::{
+    alloc::Layout,
+    mem::ManuallyDrop,
+    ptr::NonNull,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+use crate::{metadata::Metadata, ArcBumpMember, Bump, NewBumpError};
+
+/// A bump which can be allocated into from several threads at once.
+///
+/// Its allocation cursor is advanced with atomic compare-exchange
+/// operations, without locking. Objects are allocated as
+/// [`ArcBumpMember`]s, which can be shared between threads.
+pub struct SyncBump {
+    metadata: NonNull<Metadata>,
+    first_free: AtomicPtr<u8>,
+    end: NonNull<u8>,
+}
+
+// Safety:
+// The only state of the bump modified through a shared reference are
+// first_free and the count of the bump, which are both atomic
+unsafe impl Send for SyncBump {}
+
+// Safety: see Send
+unsafe impl Sync for SyncBump {}
+
+impl SyncBump {
+    /// Try to create a new bump.
+    ///
+    /// See [`Bump::try_new`].
+    pub fn try_new(capacity: usize, align: usize) -> Result<Self, NewBumpError> {
+        // The sync bump takes over the count of the bump
+        let bump = ManuallyDrop::new(Bump::try_new(capacity, align)?);
+        let metadata = bump.metadata();
+        // Safety: metadata is valid as the sync bump holds a count
+        let metadata_ref = unsafe { metadata.as_ref() };
+        metadata_ref.share();
+        let cursor = &metadata_ref.cursor;
+        let first_free = cursor.first_free.replace(cursor.end);
+        // The main region is marked full, so that all the allocations
+        // go through the atomic cursor
+        Ok(Self {
+            metadata,
+            first_free: AtomicPtr::new(first_free.as_ptr()),
+            end: cursor.end,
+        })
+    }
+
+    /// Creates a new bump.
+    ///
+    /// See [`Bump::new`].
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(capacity: usize, align: usize) -> Self {
+        Self::try_new(capacity, align).unwrap_or_else(|err| err.panic())
+    }
+
+    // Same as Bump::claim, with the atomic cursor
+    fn claim(&self, layout: Layout) -> Option<NonNull<u8>> {
+        let mut first_free = self.first_free.load(Ordering::Relaxed);
+        loop {
+            let align_offset = first_free.align_offset(layout.align());
+            let tentative_start = (first_free as usize).checked_add(align_offset)?;
+            let tentative_end = tentative_start.checked_add(layout.size())?;
+            if tentative_end > self.end.as_ptr() as usize {
+                return None;
+            }
+            // Safety: see Bump::can_fit
+            let start = unsafe { first_free.add(align_offset) };
+            // Safety: see Bump::can_fit
+            let end = unsafe { start.add(layout.size()) };
+            match self.first_free.compare_exchange_weak(
+                first_free,
+                end,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => {
+                    // Safety: metadata is valid as self holds a count
+                    unsafe { Metadata::increment(self.metadata) };
+                    return NonNull::new(start);
+                }
+                Err(current) => first_free = current,
+            }
+        }
+    }
+
+    /// Try to allocate an object with thread-safe shared ownership in the bump.
+    ///
+    /// Fails if there is not enough memory left
+    pub fn try_alloc_arc<T>(&self, value: T) -> Result<ArcBumpMember<T>, T> {
+        let Some(start) = self.claim(ArcBumpMember::<T>::entry_layout()) else {
+            return Err(value);
+        };
+        // Safety: start was just claimed for an entry
+        Ok(unsafe { ArcBumpMember::init(self.metadata, start, value) })
+    }
+
+    /// The number of bytes left for allocations
+    pub fn remaining(&self) -> usize {
+        self.end.as_ptr() as usize - self.first_free.load(Ordering::Relaxed) as usize
+    }
+}
+
+impl Drop for SyncBump {
+    fn drop(&mut self) {
+        // Safety:
+        // No other reference to metadata currently exists
+        // (only pointers)
+        unsafe { Metadata::decrement_and_drop(self.metadata) }
+    }
+}
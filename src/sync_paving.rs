@@ -0,0 +1,85 @@
+use std::sync::{PoisonError, RwLock};
+
+use crate::{paving::fits_new_bump, ArcBumpMember, NewBumpError, SyncBump};
+
+/// A paving which can be allocated into from several threads at once,
+/// for instance when shared through an [`Arc`](std::sync::Arc) by a
+/// thread pool.
+///
+/// Threads allocate concurrently in the current [`SyncBump`], and
+/// only take a lock to replace it once it is full.
+pub struct SyncPaving {
+    capacity: usize,
+    align: usize,
+    current_bump: RwLock<SyncBump>,
+}
+
+impl SyncPaving {
+    /// Try to create a new paving, which will be backed by bumps
+    /// created with correponding capacity and align.
+    ///
+    /// See [`Bump::try_new`](crate::Bump::try_new)
+    pub fn try_new(capacity: usize, align: usize) -> Result<Self, NewBumpError> {
+        Ok(Self {
+            capacity,
+            align,
+            current_bump: RwLock::new(SyncBump::try_new(capacity, align)?),
+        })
+    }
+
+    /// Creates a new paving, which will be backed by bumps
+    /// created with correponding capacity and align.
+    ///
+    /// See [`Bump::new`](crate::Bump::new)
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(capacity: usize, align: usize) -> Self {
+        Self::try_new(capacity, align).unwrap_or_else(|err| err.panic())
+    }
+
+    /// The capacity of the bumps created by the paving
+    pub fn chunk_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Try to allocate an object with thread-safe shared ownership
+    /// in the paving.
+    ///
+    /// Fails if no bump big enough can be created to accomodate
+    /// the object
+    pub fn try_alloc_arc<T>(&self, value: T) -> Result<ArcBumpMember<T>, T> {
+        if !fits_new_bump(
+            ArcBumpMember::<T>::entry_layout(),
+            self.capacity,
+            self.align,
+        ) {
+            return Err(value);
+        }
+        let value = {
+            let current = self
+                .current_bump
+                .read()
+                .unwrap_or_else(PoisonError::into_inner);
+            match current.try_alloc_arc(value) {
+                Ok(member) => return Ok(member),
+                Err(value) => value,
+            }
+        };
+        let mut current = self
+            .current_bump
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        // Another thread may have replaced the bump in the meantime
+        let value = match current.try_alloc_arc(value) {
+            Ok(member) => return Ok(member),
+            Err(value) => value,
+        };
+        let Ok(next_bump) = SyncBump::try_new(self.capacity, self.align) else {
+            return Err(value);
+        };
+        *current = next_bump;
+        current.try_alloc_arc(value)
+    }
+}
@@ -0,0 +1,136 @@
+use std::{
+    borrow::{Borrow, BorrowMut},
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+use crate::{BumpMember, RcBumpMember, WeakBumpMember};
+
+// Members behave like their pointee, as Box and Rc do.
+macro_rules! delegate_traits {
+    ($($member:ident),*) => {$(
+        impl<T: ?Sized + fmt::Debug> fmt::Debug for $member<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&**self, f)
+            }
+        }
+
+        impl<T: ?Sized + fmt::Display> fmt::Display for $member<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&**self, f)
+            }
+        }
+
+        impl<T: ?Sized + PartialEq> PartialEq for $member<T> {
+            fn eq(&self, other: &Self) -> bool {
+                **self == **other
+            }
+        }
+
+        impl<T: ?Sized + Eq> Eq for $member<T> {}
+
+        impl<T: ?Sized + PartialOrd> PartialOrd for $member<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                (**self).partial_cmp(&**other)
+            }
+        }
+
+        impl<T: ?Sized + Ord> Ord for $member<T> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                (**self).cmp(&**other)
+            }
+        }
+
+        impl<T: ?Sized> fmt::Pointer for $member<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Pointer::fmt(&$member::as_ptr(self), f)
+            }
+        }
+
+        impl<T: ?Sized + Hash> Hash for $member<T> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                (**self).hash(state)
+            }
+        }
+    )*};
+}
+
+delegate_traits!(BumpMember, RcBumpMember);
+
+// Members compare with the values they point to, and with plain
+// references and Rcs to such values, easing migrations from Rc.
+macro_rules! compare_with {
+    ($member:ident, $($other:ty),*) => {$(
+        impl<T: ?Sized + PartialEq> PartialEq<$other> for $member<T> {
+            fn eq(&self, other: &$other) -> bool {
+                **self == **other
+            }
+        }
+
+        impl<T: ?Sized + PartialOrd> PartialOrd<$other> for $member<T> {
+            fn partial_cmp(&self, other: &$other) -> Option<Ordering> {
+                (**self).partial_cmp(&**other)
+            }
+        }
+    )*};
+}
+
+compare_with!(BumpMember, &T, Rc<T>);
+compare_with!(RcBumpMember, &T, Rc<T>);
+
+macro_rules! compare_with_value {
+    ($($member:ident),*) => {$(
+        impl<T: PartialEq> PartialEq<T> for $member<T> {
+            fn eq(&self, other: &T) -> bool {
+                **self == *other
+            }
+        }
+
+        impl<T: PartialOrd> PartialOrd<T> for $member<T> {
+            fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+                (**self).partial_cmp(other)
+            }
+        }
+    )*};
+}
+
+compare_with_value!(BumpMember, RcBumpMember);
+
+// Members can stand for their pointee in lookups and generic APIs.
+macro_rules! borrow_as {
+    ($($member:ident),*) => {$(
+        impl<T: ?Sized> Borrow<T> for $member<T> {
+            fn borrow(&self) -> &T {
+                self
+            }
+        }
+
+        impl<T: ?Sized> AsRef<T> for $member<T> {
+            fn as_ref(&self) -> &T {
+                self
+            }
+        }
+    )*};
+}
+
+borrow_as!(BumpMember, RcBumpMember);
+
+impl<T: ?Sized> BorrowMut<T> for BumpMember<T> {
+    fn borrow_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T: ?Sized> AsMut<T> for BumpMember<T> {
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for WeakBumpMember<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(Weak)")
+    }
+}
@@ -0,0 +1,145 @@
+use std::{cell::RefCell, collections::HashMap, hash::Hash, mem};
+
+use crate::{NewBumpError, Paving, RcBumpMember, WeakBumpMember};
+
+// The cached values, and the number of entries after the last pruning
+struct Entries<K, T> {
+    values: HashMap<K, WeakBumpMember<T>>,
+    pruned_len: usize,
+}
+
+/// A map of values stored with shared ownership in a paving, which only
+/// keeps weak pointers to them.
+///
+/// Values are cached as long as members point to them, which makes it
+/// suited to sharing nodes built from the same key, as in a flyweight.
+/// The entries of dropped values are pruned lazily, once the map has grown
+/// twice as large since the last pruning, or when looked up.
+///
+/// Values which need no drop are never dropped while their bump is alive,
+/// see [`WeakBumpMember`], so their entries are never pruned.
+pub struct WeakCache<K, T> {
+    paving: Paving,
+    entries: RefCell<Entries<K, T>>,
+}
+
+impl<K: Eq + Hash, T> WeakCache<K, T> {
+    /// Try to create a new cache, whose backing bumps will have the
+    /// corresponding capacity and align.
+    ///
+    /// See [`Bump::try_new`](`crate::Bump::try_new`).
+    pub fn try_new(capacity: usize, align: usize) -> Result<Self, NewBumpError> {
+        Ok(Self {
+            paving: Paving::try_new(capacity, align)?,
+            entries: RefCell::new(Entries {
+                values: HashMap::new(),
+                pruned_len: 0,
+            }),
+        })
+    }
+
+    /// Creates a new cache, whose backing bumps will have the corresponding
+    /// capacity and align.
+    ///
+    /// See [`Bump::new`](`crate::Bump::new`).
+    #[cfg_attr(
+        feature = "panic_free",
+        deprecated = "may panic, which the `panic_free` feature forbids"
+    )]
+    pub fn new(capacity: usize, align: usize) -> Self {
+        Self::try_new(capacity, align).unwrap_or_else(|err| err.panic())
+    }
+
+    /// Returns the cached value of `key`, if it is still alive.
+    pub fn get(&self, key: &K) -> Option<RcBumpMember<T>> {
+        let mut entries = self.entries.try_borrow_mut().ok()?;
+        let value = entries.values.get(key)?.upgrade();
+        if value.is_some() {
+            return value;
+        }
+        let dead = entries.values.remove_entry(key);
+        drop(entries);
+        // The key is dropped once the cache is released
+        drop(dead);
+        None
+    }
+
+    /// Returns the cached value of `key`, computing and caching it
+    /// with `compute` if it is not alive anymore.
+    ///
+    /// Fails, giving back the computed value, if it cannot be allocated
+    /// or if called from `compute` or from the `Hash` or `Eq` implementation
+    /// of `K`.
+    pub fn try_get_or_insert_with(
+        &self,
+        key: K,
+        compute: impl FnOnce(&K) -> T,
+    ) -> Result<RcBumpMember<T>, T> {
+        if let Some(value) = self.get(&key) {
+            return Ok(value);
+        }
+        // The cache is not borrowed while running user code
+        let value = compute(&key);
+        let Ok(mut entries) = self.entries.try_borrow_mut() else {
+            return Err(value);
+        };
+        // compute may have cached the value itself
+        if let Some(existing) = entries.values.get(&key).and_then(WeakBumpMember::upgrade) {
+            drop(entries);
+            drop(value);
+            return Ok(existing);
+        }
+        let member = self.paving.try_alloc_rc(value)?;
+        let pruned = entries.prune_if_grown();
+        let replaced = entries.values.insert(key, member.downgrade());
+        drop(entries);
+        // Removed keys are dropped once the cache is released
+        drop(pruned);
+        drop(replaced);
+        Ok(member)
+    }
+
+    /// Removes the entries of the values which were dropped.
+    pub fn prune(&self) {
+        let Ok(mut entries) = self.entries.try_borrow_mut() else {
+            return;
+        };
+        let pruned = entries.prune();
+        drop(entries);
+        drop(pruned);
+    }
+
+    /// Returns the number of entries in the cache, including the ones
+    /// of dropped values not pruned yet.
+    pub fn len(&self) -> usize {
+        self.entries
+            .try_borrow()
+            .map_or(0, |entries| entries.values.len())
+    }
+
+    /// Returns true if the cache has no entry
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Eq + Hash, T> Entries<K, T> {
+    // Removes the entries of dropped values, and returns them
+    fn prune(&mut self) -> HashMap<K, WeakBumpMember<T>> {
+        let (alive, dead) = mem::take(&mut self.values)
+            .into_iter()
+            .partition(|(_, value)| value.upgrade().is_some());
+        self.values = alive;
+        self.pruned_len = self.values.len();
+        dead
+    }
+
+    // Prunes the entries if the map has grown twice as large since the
+    // last pruning, so that pruning takes amortized constant time
+    fn prune_if_grown(&mut self) -> HashMap<K, WeakBumpMember<T>> {
+        match self.values.len() >= 2 * self.pruned_len.max(1) {
+            true => self.prune(),
+            false => HashMap::new(),
+        }
+    }
+}